@@ -0,0 +1,187 @@
+use crate::{
+    puzzle_input,
+    solution::{Answer, Part},
+};
+use anyhow::{anyhow, Context, Result};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write},
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+pub const FILE_NAME: &str = "answers.txt";
+
+/// Answers are kept next to the inputs they belong to
+pub fn default_path(dir: Option<&Path>) -> PathBuf {
+    puzzle_input::input_dir(dir).join(FILE_NAME)
+}
+
+/// Expected answers keyed by day, part and input file name
+///
+/// Stored one per line as `<day> <part> <input file> <answer>`, `#` starts a comment
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    entries: BTreeMap<(u8, Part, String), Answer>,
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&Answer> {
+        self.entries.get(&(day, part, input.to_owned()))
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, input: &str, answer: Answer) -> Option<Answer> {
+        self.entries.insert((day, part, input.to_owned()), answer)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Missing manifest is treated as an empty one
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => content
+                .parse()
+                .with_context(|| format!("Malformed answers file {}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => {
+                Err(err).with_context(|| format!("Failed to read answers file {}", path.display()))
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Failed to write answers file {}", path.display()))
+    }
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut answers = Answers::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.splitn(4, char::is_whitespace).map(str::trim);
+            let (Some(day), Some(part), Some(input), Some(answer)) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(anyhow!(
+                    "Line {}: expected `<day> <part> <input file> <answer>`, found {line:?}",
+                    i + 1
+                ));
+            };
+            let day = day
+                .parse()
+                .with_context(|| format!("Line {}: day should be a number", i + 1))?;
+            let part = part.parse().with_context(|| format!("Line {}", i + 1))?;
+            answers.insert(day, part, input, answer.to_owned());
+        }
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("# day part input answer\n")?;
+        for ((day, part, input), answer) in self.entries.iter() {
+            f.write_fmt(format_args!("{day} {part} {input} {answer}"))?;
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer, actual: Answer },
+    Missing { actual: Answer },
+    Error(String),
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&Answer>, actual: Result<Answer>) -> Self {
+        match (expected, actual) {
+            (_, Err(err)) => Verdict::Error(format!("{err:#}")),
+            (None, Ok(actual)) => Verdict::Missing { actual },
+            (Some(expected), Ok(actual)) if expected == &actual => Verdict::Pass,
+            (Some(expected), Ok(actual)) => Verdict::Fail {
+                expected: expected.clone(),
+                actual,
+            },
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Fail { .. } | Verdict::Error(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("pass"),
+            Verdict::Fail { expected, actual } => {
+                f.write_fmt(format_args!("FAIL expected {expected}, got {actual}"))
+            }
+            Verdict::Missing { actual } => f.write_fmt(format_args!("missing, got {actual}")),
+            Verdict::Error(err) => f.write_fmt(format_args!("ERROR {err}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let input = r#"
+            # day part input answer
+            5 1 day5_fertyseed.txt 35
+            5 2 day5_fertyseed.txt 46
+            9 2 day9_example.txt -2
+        "#;
+        let answers: Answers = input.parse().unwrap();
+        assert_eq!(answers.len(), 3);
+        assert_eq!(
+            answers.get(5, Part::Two, "day5_fertyseed.txt"),
+            Some(&"46".to_string())
+        );
+        assert_eq!(answers.get(5, Part::Two, "day5_other.txt"), None);
+        assert_eq!(answers.to_string().parse::<Answers>().unwrap(), answers);
+    }
+
+    #[test]
+    fn test_malformed_line() {
+        let err = "5 1 day5.txt 35\n5 1 day5.txt"
+            .parse::<Answers>()
+            .unwrap_err();
+        assert!(err.to_string().starts_with("Line 2"));
+        assert!("x 1 day5.txt 35".parse::<Answers>().is_err());
+        assert!("5 3 day5.txt 35".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_verdict() {
+        let expected = "35".to_string();
+        assert_eq!(
+            Verdict::check(Some(&expected), Ok("35".into())),
+            Verdict::Pass
+        );
+        assert!(Verdict::check(Some(&expected), Ok("36".into())).is_failure());
+        assert!(!Verdict::check(None, Ok("36".into())).is_failure());
+        assert!(Verdict::check(None, Err(anyhow!("broken"))).is_failure());
+    }
+}
@@ -0,0 +1,277 @@
+use crate::solution::{DynSolution, Part};
+use anyhow::{anyhow, Result};
+use std::{
+    fmt::{Display, Write},
+    hint::black_box,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => f.write_str("parse"),
+            Stage::Part(part) => f.write_fmt(format_args!("part{part}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+        let mean = sorted.iter().sum::<Duration>() / len as u32;
+        let variance = if len > 1 {
+            sorted
+                .iter()
+                .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                .sum::<f64>()
+                / (len - 1) as f64
+        } else {
+            0.0
+        };
+        Some(Self {
+            runs: len,
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub variant: &'static str,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+fn sample<T>(config: &Config, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..config.warmup {
+        black_box(f()?);
+    }
+    let mut samples = Vec::with_capacity(config.runs);
+    for _ in 0..config.runs {
+        let start = Instant::now();
+        let result = f()?;
+        samples.push(start.elapsed());
+        black_box(result);
+    }
+    Stats::from_samples(&samples).ok_or_else(|| anyhow!("At least one run is required"))
+}
+
+/// Times parsing and every requested part separately, parts reuse a single parsed input
+pub fn bench(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+    config: &Config,
+) -> Result<Vec<Measurement>> {
+    let meta = solution.meta();
+    let measurement = |stage, stats| Measurement {
+        day: meta.day,
+        variant: meta.variant,
+        stage,
+        stats,
+    };
+    let mut measurements = vec![measurement(
+        Stage::Parse,
+        sample(config, || solution.parse(input))?,
+    )];
+    let parsed = solution.parse(input)?;
+    for &part in parts {
+        let stats = sample(config, || solution.run(part, &parsed))?;
+        measurements.push(measurement(Stage::Part(part), stats));
+    }
+    Ok(measurements)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(anyhow!("Format should be table, json or csv, got {s:?}")),
+        }
+    }
+}
+
+pub fn render(measurements: &[Measurement], format: Format) -> String {
+    match format {
+        Format::Table => render_table(measurements),
+        Format::Json => render_json(measurements),
+        Format::Csv => render_csv(measurements),
+    }
+}
+
+fn render_table(measurements: &[Measurement]) -> String {
+    let mut out = format!(
+        "{:>3} {:<16} {:<6} {:>5} {:>12} {:>12} {:>12} {:>12}\n",
+        "day", "variant", "stage", "runs", "min", "median", "mean", "stddev"
+    );
+    for m in measurements {
+        let _ = writeln!(
+            out,
+            "{:>3} {:<16} {:<6} {:>5} {:>12} {:>12} {:>12} {:>12}",
+            m.day,
+            m.variant,
+            m.stage.to_string(),
+            m.stats.runs,
+            format!("{:.2?}", m.stats.min),
+            format!("{:.2?}", m.stats.median),
+            format!("{:.2?}", m.stats.mean),
+            format!("{:.2?}", m.stats.stddev),
+        );
+    }
+    out
+}
+
+fn render_csv(measurements: &[Measurement]) -> String {
+    let mut out = String::from("day,variant,stage,runs,min_ns,median_ns,mean_ns,stddev_ns\n");
+    for m in measurements {
+        let _ = writeln!(
+            out,
+            "{},{},{},{},{},{},{},{}",
+            m.day,
+            m.variant,
+            m.stage,
+            m.stats.runs,
+            m.stats.min.as_nanos(),
+            m.stats.median.as_nanos(),
+            m.stats.mean.as_nanos(),
+            m.stats.stddev.as_nanos()
+        );
+    }
+    out
+}
+
+fn render_json(measurements: &[Measurement]) -> String {
+    let rows = measurements
+        .iter()
+        .map(|m| {
+            format!(
+                "  {{\"day\": {}, \"variant\": \"{}\", \"stage\": \"{}\", \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}, \"stddev_ns\": {}}}",
+                m.day,
+                m.variant,
+                m.stage,
+                m.stats.runs,
+                m.stats.min.as_nanos(),
+                m.stats.median.as_nanos(),
+                m.stats.mean.as_nanos(),
+                m.stats.stddev.as_nanos()
+            )
+        })
+        .collect::<Vec<_>>();
+    if rows.is_empty() {
+        return "[]\n".to_owned();
+    }
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::REGISTRY;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&ms(&[4, 2, 8, 6])).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        // sample variance of 2, 4, 6, 8 is 20 / 3
+        assert_eq!(stats.stddev.as_micros(), 2581);
+
+        let stats = Stats::from_samples(&ms(&[3])).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.stddev, Duration::ZERO);
+
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_bench_stages() {
+        let solution = REGISTRY.find(9, None).unwrap();
+        let config = Config { warmup: 1, runs: 3 };
+        let measurements = bench(solution, "0 3 6 9 12 15", &Part::ALL, &config).unwrap();
+        let stages: Vec<_> = measurements.iter().map(|m| m.stage.to_string()).collect();
+        assert_eq!(stages, vec!["parse", "part1", "part2"]);
+        assert!(measurements.iter().all(|m| m.stats.runs == 3));
+    }
+
+    #[test]
+    fn test_render() {
+        let stats = Stats::from_samples(&ms(&[1, 3])).unwrap();
+        let measurements = vec![Measurement {
+            day: 5,
+            variant: "bruteforce",
+            stage: Stage::Part(Part::Two),
+            stats,
+        }];
+        assert_eq!(
+            render(&measurements, Format::Csv),
+            "day,variant,stage,runs,min_ns,median_ns,mean_ns,stddev_ns\n\
+             5,bruteforce,part2,2,1000000,2000000,2000000,1414214\n"
+        );
+        assert!(render(&measurements, Format::Json)
+            .contains("\"variant\": \"bruteforce\", \"stage\": \"part2\""));
+        assert_eq!(render(&[], Format::Json), "[]\n");
+        assert!(render(&measurements, Format::Table).contains("bruteforce"));
+    }
+}
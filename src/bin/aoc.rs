@@ -0,0 +1,531 @@
+use advent_2023::{
+    answers::{self, Answers, Verdict},
+    bench, compare, day03,
+    day10::flood_fill,
+    day11::StarMap,
+    logging::{self, Level},
+    puzzle_input,
+    render::Painter,
+    solution::{DynSolution, Part, REGISTRY},
+};
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+Usage:
+    aoc run <day> [--part 1|2] [--variant <name>] [--input <file>|-]
+    aoc run --all [--part 1|2] [--inputs <dir>]
+    aoc verify [<day>] [--part 1|2] [--variant <name>] [--slow] [--input <file>]
+               [--inputs <dir>] [--answers <file>] [--record]
+    aoc bench [<day>] [--part 1|2] [--variant <name>] [--slow] [--input <file>]
+              [--inputs <dir>] [--runs <n>] [--warmup <n>] [--format table|json|csv]
+    aoc compare [<day>] [--part 1|2] [--slow] [--input <file>] [--inputs <dir>]
+    aoc render <day> [--input <file>|-] [--inputs <dir>]
+    aoc list
+    aoc help
+
+Inputs are read from <dir>/<input name>.txt, where <dir> is --inputs,
+the AOC_INPUT_DIR environment variable or src/input of this crate.
+Diagnostics are logged to stderr at --log quiet|info|debug|trace (-q for quiet),
+the AOC_LOG environment variable or info by default, answers go to stdout.
+Expected answers are read from <dir>/answers.txt unless --answers is given,
+verify --record stores the answers that are missing there.
+compare runs every variant of a day on the same input and fails when they disagree,
+naming the reference variant of the day when it disagrees with it,
+without a day it checks every day that has more than one variant.
+Slow variants, like the day 5 brute force, are left out of verify, bench and compare
+unless --slow is given or they are picked with --variant.
+render draws the puzzle of days 3, 10 and 11 to stdout, in color on a terminal
+unless the NO_COLOR environment variable is set.";
+
+#[derive(Debug, Default)]
+struct Options {
+    day: Option<u8>,
+    all: bool,
+    part: Option<Part>,
+    variant: Option<String>,
+    input: Option<PathBuf>,
+    inputs: Option<PathBuf>,
+    answers: Option<PathBuf>,
+    record: bool,
+    slow: bool,
+    bench: bench::Config,
+    format: Option<bench::Format>,
+    log: Option<Level>,
+}
+
+impl Options {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Command {
+    Run(Options),
+    Verify(Options),
+    Bench(Options),
+    Compare(Options),
+    Render(Options),
+    List,
+    Help,
+}
+
+fn next_value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String> {
+    args.next()
+        .ok_or_else(|| anyhow!("Flag {flag} requires a value"))
+}
+
+fn parse_count(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<usize> {
+    let value = next_value(args, flag)?;
+    match value.parse() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => bail!("Flag {flag} requires a positive number, got {value:?}"),
+    }
+}
+
+fn parse_options(command: &str, mut args: impl Iterator<Item = String>) -> Result<Options> {
+    let verify = command == "verify";
+    let bench = command == "bench";
+    let compare = command == "compare";
+    let render = command == "render";
+    let mut options = Options::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" | "-a" => options.all = true,
+            "--part" | "-p" => options.part = Some(next_value(&mut args, &arg)?.parse()?),
+            "--variant" | "-v" => options.variant = Some(next_value(&mut args, &arg)?),
+            "--input" | "-i" => options.input = Some(next_value(&mut args, &arg)?.into()),
+            "--inputs" => options.inputs = Some(next_value(&mut args, &arg)?.into()),
+            "--log" => options.log = Some(next_value(&mut args, &arg)?.parse()?),
+            "--quiet" | "-q" => options.log = Some(Level::Quiet),
+            "--answers" if verify => options.answers = Some(next_value(&mut args, &arg)?.into()),
+            "--record" if verify => options.record = true,
+            "--slow" if verify || bench || compare => options.slow = true,
+            "--runs" if bench => options.bench.runs = parse_count(&mut args, &arg)?,
+            "--warmup" if bench => options.bench.warmup = next_value(&mut args, &arg)?.parse()?,
+            "--format" if bench => options.format = Some(next_value(&mut args, &arg)?.parse()?),
+            day if !day.starts_with('-') && options.day.is_none() => {
+                options.day = Some(
+                    day.parse()
+                        .with_context(|| format!("Day should be a number, got {day:?}"))?,
+                )
+            }
+            other => bail!("Unexpected argument {other:?}"),
+        }
+    }
+    if (verify || bench || compare) && options.day.is_none() {
+        options.all = true;
+    }
+    match (options.day, options.all) {
+        (None, false) => bail!("Either a day or --all is required"),
+        (Some(_), true) => bail!("A day cannot be combined with --all"),
+        _ => {}
+    }
+    if render && options.all {
+        bail!("render requires a day");
+    }
+    if compare && options.variant.is_some() {
+        bail!("compare always runs every variant");
+    }
+    if options.all && options.variant.is_some() {
+        bail!("--variant requires a day");
+    }
+    if options.all && options.input.is_some() {
+        bail!("--input requires a day, use --inputs to point to a directory");
+    }
+    if (verify || bench || compare)
+        && options.input.as_deref() == Some(Path::new(puzzle_input::STDIN))
+    {
+        bail!("{command} cannot read the input from stdin");
+    }
+    Ok(options)
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command> {
+    match args.next().as_deref() {
+        Some(command @ "run") => parse_options(command, args).map(Command::Run),
+        Some(command @ "verify") => parse_options(command, args).map(Command::Verify),
+        Some(command @ "bench") => parse_options(command, args).map(Command::Bench),
+        Some(command @ "compare") => parse_options(command, args).map(Command::Compare),
+        Some(command @ "render") => parse_options(command, args).map(Command::Render),
+        Some("list") => Ok(Command::List),
+        Some("help" | "--help" | "-h") | None => Ok(Command::Help),
+        Some(other) => bail!("Unknown command {other:?}"),
+    }
+}
+
+/// Returns the input path together with the file name answers are recorded for
+fn input_location(solution: &dyn DynSolution, options: &Options) -> (String, PathBuf) {
+    let path = match options.input.as_deref() {
+        Some(path) => path.to_path_buf(),
+        None => puzzle_input::input_path(&solution.meta(), options.inputs.as_deref()),
+    };
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string());
+    (name, path)
+}
+
+fn run_solution(solution: &dyn DynSolution, options: &Options) -> Result<()> {
+    let meta = solution.meta();
+    let (_, path) = input_location(solution, options);
+    let input = puzzle_input::read_input(&path)?;
+    println!("Day {}: {} ({})", meta.day, meta.title, meta.variant);
+    let parsed = solution.parse(&input)?;
+    for part in options.parts() {
+        let answer = solution
+            .run(part, &parsed)
+            .with_context(|| format!("Day {} part {part} failed", meta.day))?;
+        println!("  Part {part}: {answer}");
+    }
+    Ok(())
+}
+
+fn run(options: Options) -> Result<bool> {
+    let solutions = match options.day {
+        Some(day) => vec![REGISTRY.find(day, options.variant.as_deref())?],
+        None => REGISTRY
+            .days()
+            .into_iter()
+            .map(|day| REGISTRY.find(day, None))
+            .collect::<Result<_>>()?,
+    };
+
+    let mut success = true;
+    for solution in solutions {
+        if let Err(err) = run_solution(solution, &options) {
+            eprintln!("Error: {err:#}");
+            success = false;
+        }
+    }
+    Ok(success)
+}
+
+/// Selects the requested variant or every variant of the day, or of all days, slow ones only with `--slow`
+fn every_variant(options: &Options) -> Result<Vec<&'static dyn DynSolution>> {
+    let included = |solution: &&dyn DynSolution| options.slow || !solution.meta().slow;
+    match (options.day, options.variant.as_deref()) {
+        (Some(day), Some(variant)) => Ok(vec![REGISTRY.find(day, Some(variant))?]),
+        (Some(day), None) => {
+            if REGISTRY.variants(day).next().is_none() {
+                bail!("Day {day} is not registered");
+            }
+            Ok(REGISTRY.variants(day).filter(included).collect())
+        }
+        (None, _) => Ok(REGISTRY.iter().filter(included).collect()),
+    }
+}
+
+fn verify_solution(
+    solution: &dyn DynSolution,
+    options: &Options,
+    answers: &Answers,
+) -> Vec<(Part, String, Verdict)> {
+    let meta = solution.meta();
+    let (name, path) = input_location(solution, options);
+    let parsed = puzzle_input::read_input(&path).and_then(|input| solution.parse(&input));
+    options
+        .parts()
+        .into_iter()
+        .map(|part| {
+            let actual = match &parsed {
+                Ok(parsed) => solution.run(part, parsed),
+                Err(err) => Err(anyhow!("{err:#}")),
+            };
+            let verdict = Verdict::check(answers.get(meta.day, part, &name), actual);
+            (part, name.clone(), verdict)
+        })
+        .collect()
+}
+
+fn verify(options: Options) -> Result<bool> {
+    let answers_path = options
+        .answers
+        .clone()
+        .unwrap_or_else(|| answers::default_path(options.inputs.as_deref()));
+    let mut answers = Answers::load(&answers_path)?;
+
+    let (mut passed, mut failed, mut missing, mut recorded) = (0, 0, 0, 0);
+    for solution in every_variant(&options)? {
+        let meta = solution.meta();
+        for (part, name, verdict) in verify_solution(solution, &options, &answers) {
+            println!(
+                "Day {} part {part} ({}) [{name}]: {verdict}",
+                meta.day, meta.variant
+            );
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Missing { actual } => {
+                    missing += 1;
+                    if options.record {
+                        answers.insert(meta.day, part, &name, actual);
+                        recorded += 1;
+                    }
+                }
+                Verdict::Fail { .. } | Verdict::Error(_) => failed += 1,
+            }
+        }
+    }
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    if recorded > 0 {
+        answers.save(&answers_path)?;
+        println!("Recorded {recorded} answers to {}", answers_path.display());
+    }
+    Ok(failed == 0)
+}
+
+fn run_bench(options: Options) -> Result<bool> {
+    let mut success = true;
+    let mut measurements = vec![];
+    for solution in every_variant(&options)? {
+        let meta = solution.meta();
+        let (_, path) = input_location(solution, &options);
+        let result = puzzle_input::read_input(&path)
+            .and_then(|input| bench::bench(solution, &input, &options.parts(), &options.bench));
+        match result {
+            Ok(measured) => measurements.extend(measured),
+            Err(err) => {
+                eprintln!("Error: Day {} ({}): {err:#}", meta.day, meta.variant);
+                success = false;
+            }
+        }
+    }
+    print!(
+        "{}",
+        bench::render(
+            &measurements,
+            options.format.unwrap_or(bench::Format::Table)
+        )
+    );
+    Ok(success)
+}
+
+fn run_compare(options: Options) -> Result<bool> {
+    let days = match options.day {
+        Some(day) => vec![day],
+        None => REGISTRY
+            .days()
+            .into_iter()
+            .filter(|&day| {
+                REGISTRY
+                    .variants(day)
+                    .filter(|s| options.slow || !s.meta().slow)
+                    .count()
+                    > 1
+            })
+            .collect(),
+    };
+
+    let mut success = true;
+    for day in days {
+        let solutions = every_variant(&Options {
+            day: Some(day),
+            slow: options.slow,
+            ..Default::default()
+        })?;
+        let meta = solutions[0].meta();
+        let (name, path) = input_location(solutions[0], &options);
+        println!("Day {day}: {} [{name}]", meta.title);
+        let input = match puzzle_input::read_input(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Error: {err:#}");
+                success = false;
+                continue;
+            }
+        };
+        let comparison = compare::cross_check(&solutions, &input, &options.parts());
+        print!("{comparison}");
+        if comparison.reference.is_none() {
+            if let Some(reference) = REGISTRY.variants(day).find(|s| s.meta().reference) {
+                println!(
+                    "  reference {} is slow and left out, --slow compares against it",
+                    reference.meta().variant
+                );
+            }
+        }
+        if comparison.agrees() {
+            println!("  all {} variants agree", solutions.len());
+        } else {
+            success = false;
+        }
+    }
+    Ok(success)
+}
+
+fn run_render(options: Options) -> Result<bool> {
+    let day = options
+        .day
+        .ok_or_else(|| anyhow!("render requires a day"))?;
+    let solution = REGISTRY.find(day, options.variant.as_deref())?;
+    let (_, path) = input_location(solution, &options);
+    let input = puzzle_input::read_input(&path)?;
+    let painter = Painter::detect();
+    let rendered = match day {
+        3 => day03::render_schematic(&day03::parse_schematic(&input)?, &painter),
+        10 => flood_fill::render_maze(&input.parse()?, &painter)?,
+        11 => input.parse::<StarMap>()?.render(&painter),
+        _ => bail!("Day {day} has no rendering"),
+    };
+    print!("{rendered}");
+    Ok(true)
+}
+
+fn list() {
+    for day in REGISTRY.days() {
+        let variants: Vec<_> = REGISTRY.variants(day).map(|s| s.meta()).collect();
+        println!(
+            "Day {day}: {} [{}]",
+            variants[0].title,
+            variants
+                .iter()
+                .map(|meta| meta.variant)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("Error: {err:#}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let log = match &command {
+        Command::Run(options)
+        | Command::Verify(options)
+        | Command::Bench(options)
+        | Command::Compare(options)
+        | Command::Render(options) => options.log,
+        Command::List | Command::Help => None,
+    };
+    if let Err(err) = logging::init(log) {
+        eprintln!("Error: {err:#}\n\n{USAGE}");
+        return ExitCode::from(2);
+    }
+    let result = match command {
+        Command::Run(options) => run(options),
+        Command::Verify(options) => verify(options),
+        Command::Bench(options) => run_bench(options),
+        Command::Compare(options) => run_compare(options),
+        Command::Render(options) => run_render(options),
+        Command::List => {
+            list();
+            Ok(true)
+        }
+        Command::Help => {
+            println!("{USAGE}");
+            Ok(true)
+        }
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("Error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> impl Iterator<Item = String> + '_ {
+        line.split_ascii_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let run = parse_options("run", args("5 --part 2 --variant cut_ranges")).unwrap();
+        assert_eq!(run.day, Some(5));
+        assert_eq!(run.part, Some(Part::Two));
+        assert_eq!(run.variant.as_deref(), Some("cut_ranges"));
+        assert!(!run.all);
+
+        let run = parse_options("run", args("--all -p 1 --inputs /tmp/inputs")).unwrap();
+        assert!(run.all);
+        assert_eq!(run.part, Some(Part::One));
+        assert_eq!(run.inputs, Some(PathBuf::from("/tmp/inputs")));
+
+        let run = parse_options("run", args("9 --input -")).unwrap();
+        assert_eq!(run.input, Some(PathBuf::from("-")));
+
+        let verify = parse_options("verify", args("--answers a.txt --record")).unwrap();
+        assert!(verify.all);
+        assert!(verify.record);
+        assert_eq!(verify.answers, Some(PathBuf::from("a.txt")));
+
+        let bench = parse_options("bench", args("5 --runs 20 --warmup 0 --format csv")).unwrap();
+        assert_eq!(bench.day, Some(5));
+        assert_eq!(bench.bench.runs, 20);
+        assert_eq!(bench.bench.warmup, 0);
+        assert_eq!(bench.format, Some(bench::Format::Csv));
+
+        let compare = parse_options("compare", args("--part 2")).unwrap();
+        assert!(compare.all);
+        assert!(!compare.slow);
+        assert!(parse_options("compare", args("5 --slow")).unwrap().slow);
+        assert_eq!(compare.log, None);
+
+        let run = parse_options("run", args("6 --log trace")).unwrap();
+        assert_eq!(run.log, Some(Level::Trace));
+        let run = parse_options("run", args("6 -q")).unwrap();
+        assert_eq!(run.log, Some(Level::Quiet));
+        assert_eq!(compare.part, Some(Part::Two));
+    }
+
+    #[test]
+    fn test_parse_options_errors() {
+        assert!(parse_options("run", args("")).is_err());
+        assert!(parse_options("run", args("5 --all")).is_err());
+        assert!(parse_options("run", args("--all --variant scanline")).is_err());
+        assert!(parse_options("run", args("five")).is_err());
+        assert!(parse_options("run", args("5 --part 3")).is_err());
+        assert!(parse_options("run", args("5 --part")).is_err());
+        assert!(parse_options("run", args("5 6")).is_err());
+        assert!(parse_options("run", args("--all --input -")).is_err());
+        assert!(parse_options("run", args("5 --record")).is_err());
+        assert!(parse_options("run", args("5 --slow")).is_err());
+    }
+
+    #[test]
+    fn test_every_variant_skips_slow() {
+        let names = |line| {
+            every_variant(&parse_options("bench", args(line)).unwrap())
+                .unwrap()
+                .iter()
+                .map(|s| s.meta().variant)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names("5"), ["cut_ranges", "path_compressed"]);
+        assert_eq!(
+            names("5 --slow"),
+            ["cut_ranges", "path_compressed", "bruteforce"]
+        );
+        assert_eq!(names("5 --variant bruteforce"), ["bruteforce"]);
+        assert!(!names("").contains(&"bruteforce"));
+        assert!(parse_options("verify", args("5 --input -")).is_err());
+        assert!(parse_options("bench", args("5 --runs 0")).is_err());
+        assert!(parse_options("bench", args("5 --format xml")).is_err());
+        assert!(parse_options("run", args("5 --runs 3")).is_err());
+        assert!(parse_options("compare", args("5 --variant scanline")).is_err());
+        assert!(parse_options("compare", args("5 --input -")).is_err());
+        assert!(parse_options("run", args("6 --log loud")).is_err());
+        assert!(parse_options("render", args("--all")).is_err());
+        assert!(parse_options("render", args("10 --input -")).is_ok());
+    }
+}
@@ -1,110 +1,58 @@
-use advent_2023::number_trie::NumberTrie;
-use once_cell::sync::Lazy;
-
-const SPELLED_NUMBERS: [(&str, usize); 9] = [
-    ("one", 1),
-    ("two", 2),
-    ("three", 3),
-    ("four", 4),
-    ("five", 5),
-    ("six", 6),
-    ("seven", 7),
-    ("eight", 8),
-    ("nine", 9),
-];
-
-static SPELLED_NUMBERS_TRIE: Lazy<NumberTrie> = Lazy::new(|| SPELLED_NUMBERS.to_vec().into());
-static REVERSED_SPELLED_NUMBERS_TRIE: Lazy<NumberTrie> = Lazy::new(|| {
-    SPELLED_NUMBERS
-        .iter()
-        .fold(NumberTrie::default(), |mut root, &(spelled, value)| {
-            let reversed = spelled.chars().rev().collect::<String>();
-            root.append(&reversed, value);
-            root
-        })
-});
-
-fn find_number(chars: &mut impl Iterator<Item = char>, trie: &NumberTrie) -> Option<String> {
-    let mut trie_nodes: Vec<&NumberTrie> = vec![];
-    for ch in chars {
-        if ch.is_ascii_digit() {
-            return Some(ch.to_string());
-        }
-        for i in (0..trie_nodes.len()).rev() {
-            if let Some(child) = trie_nodes[i].children.get(&ch) {
-                if let Some(number) = child.number {
-                    return Some(number.to_string());
-                }
-
-                trie_nodes[i] = child;
-            } else {
-                trie_nodes.remove(i);
+use advent_2023::{
+    day01::{self, explain, Dictionary, Overlap, SPELLED_NUMBERS_DICTIONARY},
+    logging, puzzle_input,
+    solution::REGISTRY,
+};
+use anyhow::{anyhow, Result};
+use std::{env, path::PathBuf};
+
+/// Usage: day1_trebuchet [<input>|-] [<dictionary>] [--explain text|json]
+///                       [--overlap overlapping|greedy|longest]
+///
+/// A dictionary or an overlap policy changes how part 2 is read, explain prints how every line was read
+fn main() -> Result<()> {
+    logging::init(None)?;
+    let mut paths = vec![];
+    let mut format = None;
+    let mut overlap = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--explain" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Flag --explain requires a value"))?;
+                format = Some(value.parse::<explain::Format>()?);
             }
-        }
-        if let Some(node) = trie.children.get(&ch) {
-            trie_nodes.push(node)
-        }
-    }
-    None
-}
-
-pub fn solution(inp: &str, trie: &NumberTrie, rev_trie: &NumberTrie) -> usize {
-    let mut sum = 0;
-    for line in inp.split('\n') {
-        let mut chars = line.chars();
-        let first = find_number(&mut chars, trie);
-        let last = find_number(&mut chars.rev(), rev_trie);
-        if let (Some(left), Some(right)) = (first.as_ref(), last.as_ref().or(first.as_ref())) {
-            let num: usize = format!("{}{}", left, right).parse().unwrap();
-            sum += num;
+            "--overlap" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Flag --overlap requires a value"))?;
+                overlap = Some(value.parse::<Overlap>()?);
+            }
+            _ => paths.push(PathBuf::from(arg)),
         }
     }
-    sum
-}
-
-fn main() {
-    let input = include_str!("../input/day1_trebuchet.txt");
-
-    let empty_trie = NumberTrie::default();
-    println!("{}", solution(input, &empty_trie, &empty_trie));
-    println!(
-        "{}",
-        solution(input, &SPELLED_NUMBERS_TRIE, &REVERSED_SPELLED_NUMBERS_TRIE)
-    );
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_base_solution() {
-        let input = r#"
-            1abc2
-            pqr3stu8vwx
-            a1b2c3d4e5f
-            treb7uchet
-        "#;
-        assert_eq!(
-            solution(input, &SPELLED_NUMBERS_TRIE, &REVERSED_SPELLED_NUMBERS_TRIE),
-            12 + 38 + 15 + 77
-        );
+    let solution = REGISTRY.find(1, None)?;
+    let input = puzzle_input::load(&solution.meta(), paths.first().map(PathBuf::as_path), None)?;
+    let dictionary = paths
+        .get(1)
+        .map(|path| Dictionary::load(path))
+        .transpose()?;
+
+    let numbers = dictionary.as_ref().unwrap_or(&SPELLED_NUMBERS_DICTIONARY);
+    if let Some(format) = format {
+        let explanations = explain::explain(&input, numbers, overlap.unwrap_or_default());
+        print!("{}", explain::render(&explanations, format));
+        return Ok(());
     }
-
-    #[test]
-    fn test_spelled_digits_solution() {
-        let input = r#"
-            two1nine
-            eightwothree
-            abcone2threexyz
-            xtwone3four
-            4nineeightseven2
-            zoneight234
-            7pqrstsixteen
-        "#;
-        assert_eq!(
-            solution(input, &SPELLED_NUMBERS_TRIE, &REVERSED_SPELLED_NUMBERS_TRIE),
-            29 + 83 + 13 + 24 + 42 + 14 + 76
-        );
+    let (part_one, part_two) = solution.solve(&input)?;
+    println!("{part_one}");
+    if dictionary.is_some() || overlap.is_some() {
+        let overlap = overlap.unwrap_or_default();
+        println!("{}", day01::solution(&input, numbers, overlap)?);
+    } else {
+        println!("{part_two}");
     }
+    Ok(())
 }
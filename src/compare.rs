@@ -0,0 +1,278 @@
+use crate::solution::{Answer, DynSolution, Part};
+use std::{
+    fmt::{Display, Write},
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    pub answer: Result<Answer, String>,
+    pub elapsed: Duration,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.answer {
+            Ok(answer) => f.write_fmt(format_args!("{answer} ({:.2?})", self.elapsed)),
+            Err(_) => f.write_fmt(format_args!("error ({:.2?})", self.elapsed)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantRun {
+    pub variant: &'static str,
+    pub parse: Duration,
+    pub parts: Vec<(Part, Outcome)>,
+}
+
+impl VariantRun {
+    fn outcome(&self, part: Part) -> Option<&Outcome> {
+        self.parts
+            .iter()
+            .find(|(p, _)| p == &part)
+            .map(|(_, outcome)| outcome)
+    }
+}
+
+/// Answers of every variant of a day on the same input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub parts: Vec<Part>,
+    pub runs: Vec<VariantRun>,
+    /// Variant whose [`Meta`](crate::solution::Meta) marks it as the reference, when it ran
+    pub reference: Option<&'static str>,
+}
+
+impl Comparison {
+    /// Parts where a variant failed or answered differently from the others, the reference included
+    pub fn disagreements(&self) -> Vec<Part> {
+        self.parts
+            .iter()
+            .copied()
+            .filter(|&part| {
+                let answers = self
+                    .runs
+                    .iter()
+                    .filter_map(|run| run.outcome(part))
+                    .map(|outcome| outcome.answer.as_ref().ok())
+                    .collect::<Vec<_>>();
+                answers.iter().any(Option::is_none)
+                    || answers.windows(2).any(|pair| pair[0] != pair[1])
+            })
+            .collect()
+    }
+
+    pub fn agrees(&self) -> bool {
+        self.disagreements().is_empty()
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .runs
+            .iter()
+            .map(|run| run.variant.len())
+            .max()
+            .unwrap_or_default()
+            .max("variant".len());
+        let mut header = format!("  {:width$}  {:>12}", "variant", "parse");
+        for part in self.parts.iter() {
+            header.push_str(&format!("  {:<28}", format!("part {part}")));
+        }
+        f.write_str(header.trim_end())?;
+        f.write_char('\n')?;
+        for run in self.runs.iter() {
+            let mut row = format!(
+                "  {:width$}  {:>12}",
+                run.variant,
+                format!("{:.2?}", run.parse)
+            );
+            for (_, outcome) in run.parts.iter() {
+                row.push_str(&format!("  {:<28}", outcome.to_string()));
+            }
+            f.write_str(row.trim_end())?;
+            f.write_char('\n')?;
+        }
+        for part in self.disagreements() {
+            match self.reference {
+                Some(reference) => {
+                    f.write_fmt(format_args!("  part {part} disagrees with {reference}:"))?
+                }
+                None => f.write_fmt(format_args!("  part {part} disagrees:"))?,
+            }
+            for run in self.runs.iter() {
+                match run.outcome(part).map(|outcome| &outcome.answer) {
+                    Some(Ok(answer)) => f.write_fmt(format_args!(" {}={answer}", run.variant))?,
+                    Some(Err(err)) => {
+                        f.write_fmt(format_args!(" {} failed: {err};", run.variant))?
+                    }
+                    None => {}
+                }
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Runs every solution on the same input and collects their answers with timings
+pub fn cross_check(solutions: &[&dyn DynSolution], input: &str, parts: &[Part]) -> Comparison {
+    let runs = solutions
+        .iter()
+        .map(|solution| {
+            let (parsed, parse) = time(|| solution.parse(input));
+            let parts = parts
+                .iter()
+                .map(|&part| {
+                    let (answer, elapsed) = match &parsed {
+                        Ok(parsed) => time(|| solution.run(part, parsed)),
+                        Err(err) => (Err(anyhow::anyhow!("{err:#}")), Duration::ZERO),
+                    };
+                    let answer = answer.map_err(|err| format!("{err:#}"));
+                    (part, Outcome { answer, elapsed })
+                })
+                .collect();
+            VariantRun {
+                variant: solution.meta().variant,
+                parse,
+                parts,
+            }
+        })
+        .collect();
+    Comparison {
+        day: solutions.first().map(|s| s.meta().day).unwrap_or_default(),
+        parts: parts.to_vec(),
+        runs,
+        reference: solutions
+            .iter()
+            .map(|s| s.meta())
+            .find(|meta| meta.reference)
+            .map(|meta| meta.variant),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::REGISTRY;
+
+    fn variants(day: u8) -> Vec<&'static dyn DynSolution> {
+        REGISTRY.variants(day).collect()
+    }
+
+    #[test]
+    fn test_day5_variants_agree() {
+        let input = r#"
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+
+        water-to-light map:
+        88 18 7
+        18 25 70
+
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+
+        humidity-to-location map:
+        60 56 37
+        56 93 4
+        "#;
+        let comparison = cross_check(&variants(5), input, &Part::ALL);
+        assert_eq!(comparison.runs.len(), 3);
+        assert_eq!(comparison.reference, Some("bruteforce"));
+        assert!(comparison.agrees(), "{comparison}");
+    }
+
+    #[test]
+    fn test_day10_variants_agree() {
+        for input in [
+            ".....\n.F-7.\n.S.|.\n.L-J.\n.....",
+            r#"
+            .F----7F7F7F7F-7....
+            .|F--7||||||||FJ....
+            .||.FJ||||||||L7....
+            FJL7L7LJLJ||LJ.L-7..
+            L--J.L7...LJS7F-7L7.
+            ....F-J..F7FJ|L7L7L7
+            ....L7.F7||L7|.L7L7|
+            .....|FJLJ|FJ|F7|.LJ
+            ....FJL-7.||.||||...
+            ....L---J.LJ.LJLJ...
+            "#,
+        ] {
+            let comparison = cross_check(&variants(10), input, &Part::ALL);
+            assert!(comparison.agrees(), "{comparison}");
+        }
+    }
+
+    #[test]
+    fn test_disagreements() {
+        let outcome = |answer: Result<&str, &str>| Outcome {
+            answer: answer.map(String::from).map_err(String::from),
+            elapsed: Duration::ZERO,
+        };
+        let run = |variant, one, two| VariantRun {
+            variant,
+            parse: Duration::ZERO,
+            parts: vec![(Part::One, outcome(one)), (Part::Two, outcome(two))],
+        };
+        let comparison = Comparison {
+            day: 10,
+            parts: Part::ALL.to_vec(),
+            runs: vec![run("a", Ok("4"), Ok("1")), run("b", Ok("4"), Ok("0"))],
+            reference: None,
+        };
+        assert_eq!(comparison.disagreements(), vec![Part::Two]);
+        assert!(comparison.to_string().contains("part 2 disagrees: a=1 b=0"));
+
+        let comparison = Comparison {
+            runs: vec![
+                run("a", Ok("4"), Ok("1")),
+                run("b", Ok("4"), Ok("0")),
+                run("c", Ok("4"), Ok("1")),
+            ],
+            reference: Some("b"),
+            ..comparison
+        };
+        assert!(comparison
+            .to_string()
+            .contains("part 2 disagrees with b: a=1 b=0 c=1"));
+
+        let comparison = Comparison {
+            day: 10,
+            parts: Part::ALL.to_vec(),
+            runs: vec![run("a", Ok("4"), Ok("1")), run("b", Err("boom"), Ok("1"))],
+            reference: Some("a"),
+        };
+        assert_eq!(comparison.disagreements(), vec![Part::One]);
+    }
+}
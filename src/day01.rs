@@ -0,0 +1,402 @@
+//! Day 1: Trebuchet?!, calibration values are the first and the last digit of a line
+
+use crate::{
+    number_trie::{Match, NumberAutomaton, NumberTrie},
+    parse_error::{parse_token, ParseError},
+    solution::{Answer, Meta, Registry, Solution},
+};
+use anyhow::{anyhow, Context, Result};
+use once_cell::sync::Lazy;
+use std::{cmp::Reverse, fs, path::Path, str::FromStr};
+
+pub mod explain;
+pub mod stream;
+
+const DAY: u8 = 1;
+
+/// Digits as they are written
+pub const DIGITS: [(&str, usize); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// Digits spelled out in English
+pub const SPELLED_NUMBERS: [(&str, usize); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub static DIGITS_DICTIONARY: Lazy<Dictionary> = Lazy::new(|| Dictionary::new(DIGITS));
+/// Digits together with their English names
+pub static SPELLED_NUMBERS_DICTIONARY: Lazy<Dictionary> =
+    Lazy::new(|| Dictionary::with_digits(SPELLED_NUMBERS));
+
+fn fold_case(ch: char) -> char {
+    ch.to_lowercase().next().unwrap_or(ch)
+}
+
+/// Words standing for numbers, matched regardless of case
+///
+/// A dictionary file has a `<word> <number>` pair per line, blank lines and lines starting
+/// with `#` are skipped and the digits are always recognized
+#[derive(Debug, Clone)]
+pub struct Dictionary {
+    automaton: NumberAutomaton,
+}
+
+impl Dictionary {
+    /// Only the given words are recognized, the later of two equal words wins
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = (S, usize)>) -> Self {
+        let trie: NumberTrie = words
+            .into_iter()
+            .map(|(word, number)| {
+                let word: String = word.as_ref().chars().map(fold_case).collect();
+                (word, number)
+            })
+            .collect();
+        Self {
+            automaton: trie.into(),
+        }
+    }
+
+    /// Digits followed by the given words
+    pub fn with_digits<S: AsRef<str>>(words: impl IntoIterator<Item = (S, usize)>) -> Self {
+        let digits = DIGITS.map(|(digit, number)| (digit.to_owned(), number));
+        let words = words
+            .into_iter()
+            .map(|(word, number)| (word.as_ref().to_owned(), number));
+        Self::new(digits.into_iter().chain(words))
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read dictionary {}", path.display()))?;
+        text.parse()
+            .with_context(|| format!("Malformed dictionary {}", path.display()))
+    }
+
+    /// Every occurrence of the words, positions count chars of the line
+    pub fn find_iter<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match<usize>> + 'a {
+        self.automaton
+            .find_iter(line.chars().map(fold_case))
+            .map(|m| Match {
+                start: m.start,
+                end: m.end,
+                value: *m.value,
+            })
+    }
+}
+
+impl FromStr for Dictionary {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let mut words = vec![];
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            let (Some(word), Some(number), None) = (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(ParseError::at(DAY, s, line, "`<word> <number>`"));
+            };
+            words.push((word, parse_token(DAY, s, number, "a number")?));
+        }
+        Ok(Self::with_digits(words))
+    }
+}
+
+/// How words that share characters are read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overlap {
+    /// Every match counts, `twone` is both 2 and 1
+    #[default]
+    Overlapping,
+    /// Left to right, the word that starts first is taken, the shortest one on a tie, and the ones it overlaps are dropped
+    Greedy,
+    /// Left to right, the longest word is taken and the ones it overlaps are dropped
+    Longest,
+}
+
+impl Overlap {
+    /// Matches left after dropping the overlapped ones, in the order they start
+    fn tokenize(self, matches: impl Iterator<Item = Match<usize>>) -> Vec<Match<usize>> {
+        let mut matches: Vec<_> = matches.collect();
+        match self {
+            Overlap::Longest => matches.sort_by_key(|m| (m.start, Reverse(m.end))),
+            _ => matches.sort_by_key(|m| (m.start, m.end)),
+        }
+        if self != Overlap::Overlapping {
+            let mut end = 0;
+            matches.retain(|m| {
+                let keep = m.start >= end;
+                if keep {
+                    end = m.end;
+                }
+                keep
+            });
+        }
+        matches
+    }
+}
+
+impl FromStr for Overlap {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "overlapping" => Ok(Overlap::Overlapping),
+            "greedy" => Ok(Overlap::Greedy),
+            "longest" => Ok(Overlap::Longest),
+            _ => Err(anyhow!(
+                "Overlap should be overlapping, greedy or longest, got {s:?}"
+            )),
+        }
+    }
+}
+
+/// Match that starts first and the one that starts last
+fn first_and_last(
+    mut matches: impl Iterator<Item = Match<usize>>,
+    overlap: Overlap,
+) -> Option<(Match<usize>, Match<usize>)> {
+    if overlap != Overlap::Overlapping {
+        let tokens = overlap.tokenize(matches);
+        return Some((*tokens.first()?, *tokens.last()?));
+    }
+    // Nothing is dropped, so one pass is enough
+    let m = matches.next()?;
+    Some(matches.fold((m, m), |(first, last), m| {
+        (
+            if m.start < first.start { m } else { first },
+            if m.start >= last.start { m } else { last },
+        )
+    }))
+}
+
+/// First and last number of a line
+pub fn find_numbers(line: &str, numbers: &Dictionary, overlap: Overlap) -> Option<(usize, usize)> {
+    let (first, last) = first_and_last(numbers.find_iter(line), overlap)?;
+    Some((first.value, last.value))
+}
+
+/// Leading digit of the first number and trailing digit of the last one, `twelve` alone gives 12
+pub fn calibration_value(first: usize, last: usize) -> usize {
+    let mut leading = first;
+    while leading >= 10 {
+        leading /= 10;
+    }
+    leading * 10 + last % 10
+}
+
+/// Calibration lines only hold letters and digits
+fn check_calibration(input: &str) -> Result<(), ParseError> {
+    for line in input.lines().map(str::trim) {
+        if let Some(i) = line.find(|ch: char| !ch.is_alphanumeric()) {
+            return Err(ParseError::at(
+                DAY,
+                input,
+                &line[i..],
+                "a letter or a digit",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Sum of calibration values, only the words of `numbers` are recognized
+pub fn solution(inp: &str, numbers: &Dictionary, overlap: Overlap) -> Result<usize> {
+    sum_lines(inp.split('\n'), numbers, overlap)
+}
+
+/// Same as [`solution`] over lines that are already split
+pub fn sum_lines<'a>(
+    lines: impl IntoIterator<Item = &'a str>,
+    numbers: &Dictionary,
+    overlap: Overlap,
+) -> Result<usize> {
+    let mut sum: usize = 0;
+    for (i, line) in lines.into_iter().enumerate() {
+        if let Some((first, last)) = find_numbers(line, numbers, overlap) {
+            sum = sum
+                .checked_add(calibration_value(first, last))
+                .with_context(|| format!("Line {}: sum of calibration values overflows", i + 1))?;
+        }
+    }
+    Ok(sum)
+}
+
+pub struct Trebuchet;
+
+impl Solution for Trebuchet {
+    /// Calibration lines, blank ones left out
+    type Input = Vec<String>;
+
+    const META: Meta = Meta::new(1, "Trebuchet?!", "default", "day1_trebuchet");
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        check_calibration(input)?;
+        Ok(input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_owned)
+            .collect())
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let lines = input.iter().map(String::as_str);
+        Ok(sum_lines(lines, &DIGITS_DICTIONARY, Overlap::Overlapping)?.to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let lines = input.iter().map(String::as_str);
+        Ok(sum_lines(lines, &SPELLED_NUMBERS_DICTIONARY, Overlap::Overlapping)?.to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Trebuchet);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_solution() {
+        let input = r#"
+            1abc2
+            pqr3stu8vwx
+            a1b2c3d4e5f
+            treb7uchet
+        "#;
+        assert_eq!(
+            solution(input, &SPELLED_NUMBERS_DICTIONARY, Overlap::Overlapping).unwrap(),
+            12 + 38 + 15 + 77
+        );
+    }
+
+    #[test]
+    fn test_find_numbers() {
+        let numbers = &SPELLED_NUMBERS_DICTIONARY;
+        assert_eq!(
+            find_numbers("xtwone", numbers, Overlap::Overlapping),
+            Some((2, 1))
+        );
+        assert_eq!(
+            find_numbers("7pqrstsixteen", numbers, Overlap::Overlapping),
+            Some((7, 6))
+        );
+        assert_eq!(
+            find_numbers("sevenine", &DIGITS_DICTIONARY, Overlap::Overlapping),
+            None
+        );
+        assert_eq!(
+            find_numbers("treb7uchet", &DIGITS_DICTIONARY, Overlap::Overlapping),
+            Some((7, 7))
+        );
+    }
+
+    #[test]
+    fn test_dictionary() {
+        let german: Dictionary = "# Deutsch\n\nnull 0\neins 1\nzwei 2\ndrei 3\nzwölf 12\n"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            find_numbers("ZWEIundDrei", &german, Overlap::Overlapping),
+            Some((2, 3))
+        );
+        assert_eq!(
+            find_numbers("nullacht", &german, Overlap::Overlapping),
+            Some((0, 0))
+        );
+        assert_eq!(
+            solution("Zwölf\nxeins7\n9null", &german, Overlap::Overlapping).unwrap(),
+            12 + 17 + 90
+        );
+
+        let english = Dictionary::with_digits([("Ten", 10), ("twelve", 12), ("zero", 0)]);
+        assert_eq!(
+            find_numbers("tenTWELVE", &english, Overlap::Overlapping),
+            Some((10, 12))
+        );
+        assert_eq!(
+            solution("tenTWELVE\ntwelve\n3zero", &english, Overlap::Overlapping).unwrap(),
+            12 + 12 + 30
+        );
+        assert_eq!(calibration_value(345, 12), 32);
+
+        let err = "eins 1\nzwei".parse::<Dictionary>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = "eins eins".parse::<Dictionary>().unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+    }
+
+    #[test]
+    fn test_overlap() {
+        let numbers = &SPELLED_NUMBERS_DICTIONARY;
+        let read = |line, overlap| find_numbers(line, numbers, overlap);
+        // Overlapping reads `two` and `one`, the others drop `one` as it shares the `o`
+        assert_eq!(read("twone", Overlap::Overlapping), Some((2, 1)));
+        assert_eq!(read("twone", Overlap::Greedy), Some((2, 2)));
+        assert_eq!(read("twone", Overlap::Longest), Some((2, 2)));
+        // Greedy and Longest drop `two` as it overlaps `eight`, but the first and the last number stay the same
+        for overlap in [Overlap::Overlapping, Overlap::Greedy, Overlap::Longest] {
+            assert_eq!(read("eightwothree", overlap), Some((8, 3)));
+        }
+        assert_eq!(read("xtwone3", Overlap::Greedy), Some((2, 3)));
+
+        // With `six` and `sixteen` the policies differ on which word comes first
+        let teens = Dictionary::with_digits([("six", 6), ("sixteen", 16), ("teen", 10)]);
+        let read = |line, overlap| find_numbers(line, &teens, overlap);
+        assert_eq!(read("sixteen", Overlap::Overlapping), Some((6, 10)));
+        assert_eq!(read("sixteen", Overlap::Greedy), Some((6, 10)));
+        assert_eq!(read("sixteen", Overlap::Longest), Some((16, 16)));
+        assert_eq!(
+            solution("sixteen\n6teen", &teens, Overlap::Longest).unwrap(),
+            16 + 60
+        );
+        assert!("lazy".parse::<Overlap>().is_err());
+    }
+
+    #[test]
+    fn test_malformed_line() {
+        let err = Trebuchet.parse("1abc2\npqr3 stu8vwx").unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+
+    #[test]
+    fn test_spelled_digits_solution() {
+        let input = r#"
+            two1nine
+            eightwothree
+            abcone2threexyz
+            xtwone3four
+            4nineeightseven2
+            zoneight234
+            7pqrstsixteen
+        "#;
+        assert_eq!(
+            solution(input, &SPELLED_NUMBERS_DICTIONARY, Overlap::Overlapping).unwrap(),
+            29 + 83 + 13 + 24 + 42 + 14 + 76
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Shows how every line was read, for calibration values that are disputed
+
+use super::{calibration_value, first_and_last, Dictionary, Overlap};
+use crate::number_trie::Match;
+use anyhow::{anyhow, Result};
+use std::{fmt::Write, str::FromStr};
+
+/// Matches of a line with the ones picked, offsets count bytes of the trimmed line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    /// Counted from 1
+    pub line: usize,
+    pub text: &'a str,
+    pub matches: Vec<Match<usize>>,
+    /// Indices into `matches` of the first and the last number
+    pub chosen: Option<(usize, usize)>,
+}
+
+impl Explanation<'_> {
+    pub fn value(&self) -> Option<usize> {
+        self.chosen.map(|(first, last)| {
+            calibration_value(self.matches[first].value, self.matches[last].value)
+        })
+    }
+
+    /// Lines without a number are skipped by the solution
+    pub fn has_digit(&self) -> bool {
+        self.chosen.is_some()
+    }
+
+    fn word(&self, m: &Match<usize>) -> &str {
+        &self.text[m.start..m.end]
+    }
+
+    fn mark(&self, i: usize) -> &'static str {
+        match self.chosen {
+            Some((first, last)) if first == i && last == i => "first last",
+            Some((first, _)) if first == i => "first",
+            Some((_, last)) if last == i => "last",
+            _ => "",
+        }
+    }
+}
+
+/// Every non-blank line of the document with the numbers found in it
+pub fn explain<'a>(inp: &'a str, numbers: &Dictionary, overlap: Overlap) -> Vec<Explanation<'a>> {
+    inp.split('\n')
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, text)| !text.is_empty())
+        .map(|(line, text)| {
+            let offsets: Vec<usize> = text
+                .char_indices()
+                .map(|(offset, _)| offset)
+                .chain([text.len()])
+                .collect();
+            let matches: Vec<Match<usize>> = numbers
+                .find_iter(text)
+                .map(|m| Match {
+                    start: offsets[m.start],
+                    end: offsets[m.end],
+                    value: m.value,
+                })
+                .collect();
+            // Same choice as the solution makes, with the values swapped for indices
+            let indexed = matches
+                .iter()
+                .enumerate()
+                .map(|(i, m)| Match { value: i, ..*m });
+            let chosen =
+                first_and_last(indexed, overlap).map(|(first, last)| (first.value, last.value));
+            Explanation {
+                line,
+                text,
+                matches,
+                chosen,
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(anyhow!("Format should be text or json, got {s:?}")),
+        }
+    }
+}
+
+pub fn render(explanations: &[Explanation], format: Format) -> String {
+    match format {
+        Format::Text => render_text(explanations),
+        Format::Json => render_json(explanations),
+    }
+}
+
+fn render_text(explanations: &[Explanation]) -> String {
+    let mut out = String::new();
+    for explanation in explanations {
+        let _ = match explanation.value() {
+            Some(value) => writeln!(
+                out,
+                "line {}: {} -> {value}",
+                explanation.line, explanation.text
+            ),
+            None => writeln!(
+                out,
+                "line {}: {} -> no digit, skipped",
+                explanation.line, explanation.text
+            ),
+        };
+        for (i, m) in explanation.matches.iter().enumerate() {
+            let row = format!(
+                "  {:<12} {:<10} {:>3} {}",
+                format!("{}..{}", m.start, m.end),
+                explanation.word(m),
+                m.value,
+                explanation.mark(i)
+            );
+            let _ = writeln!(out, "{}", row.trim_end());
+        }
+    }
+    out
+}
+
+/// String literal with the characters JSON requires to be escaped
+fn json_string(text: &str) -> String {
+    let mut out = String::from('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            ch if ch.is_control() => {
+                let _ = write!(out, "\\u{:04x}", ch as u32);
+            }
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_option(value: Option<usize>) -> String {
+    value.map_or("null".to_owned(), |value| value.to_string())
+}
+
+fn render_json(explanations: &[Explanation]) -> String {
+    let rows = explanations
+        .iter()
+        .map(|explanation| {
+            let matches = explanation
+                .matches
+                .iter()
+                .map(|m| {
+                    format!(
+                        "{{\"start\": {}, \"end\": {}, \"text\": {}, \"value\": {}}}",
+                        m.start,
+                        m.end,
+                        json_string(explanation.word(m)),
+                        m.value
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!(
+                "  {{\"line\": {}, \"text\": {}, \"matches\": [{}], \"first\": {}, \"last\": {}, \"value\": {}, \"no_digit\": {}}}",
+                explanation.line,
+                json_string(explanation.text),
+                matches.join(", "),
+                json_option(explanation.chosen.map(|(first, _)| first)),
+                json_option(explanation.chosen.map(|(_, last)| last)),
+                json_option(explanation.value()),
+                !explanation.has_digit()
+            )
+        })
+        .collect::<Vec<_>>();
+    if rows.is_empty() {
+        return "[]\n".to_owned();
+    }
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01::{solution, SPELLED_NUMBERS_DICTIONARY};
+
+    #[test]
+    fn test_explain() {
+        let input = "xtwone3four\n\n  abc\nzwölfeins";
+        let dictionary = Dictionary::with_digits([("zwölf", 12), ("eins", 1)]);
+        let explanations = explain(input, &SPELLED_NUMBERS_DICTIONARY, Overlap::Overlapping);
+        assert_eq!(explanations.len(), 3);
+        let first = &explanations[0];
+        let found: Vec<_> = first
+            .matches
+            .iter()
+            .map(|m| (m.start, m.end, m.value))
+            .collect();
+        assert_eq!(found, vec![(1, 4, 2), (3, 6, 1), (6, 7, 3), (7, 11, 4)]);
+        assert_eq!(first.chosen, Some((0, 3)));
+        assert_eq!(first.value(), Some(24));
+        assert_eq!(explanations[1].line, 3);
+        assert!(!explanations[1].has_digit());
+
+        let explanations = explain(input, &dictionary, Overlap::Overlapping);
+        assert_eq!(
+            explanations[2].matches[1],
+            Match {
+                start: 6,
+                end: 10,
+                value: 1
+            }
+        );
+        assert_eq!(explanations[2].value(), Some(11));
+        let total: usize = explanations.iter().filter_map(Explanation::value).sum();
+        assert_eq!(
+            total,
+            solution(input, &dictionary, Overlap::Overlapping).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_explain_overlap() {
+        let explanations = explain("xtwone", &SPELLED_NUMBERS_DICTIONARY, Overlap::Greedy);
+        assert_eq!(explanations[0].matches.len(), 2);
+        assert_eq!(explanations[0].chosen, Some((0, 0)));
+        assert_eq!(explanations[0].value(), Some(22));
+    }
+
+    #[test]
+    fn test_render() {
+        let explanations = explain(
+            "7one\nab\"c",
+            &SPELLED_NUMBERS_DICTIONARY,
+            Overlap::Overlapping,
+        );
+        let text = render(&explanations, Format::Text);
+        assert!(text.starts_with("line 1: 7one -> 71\n"));
+        assert!(text.contains("line 2: ab\"c -> no digit, skipped\n"));
+        assert_eq!(
+            render(&explanations, Format::Json),
+            "[\n  {\"line\": 1, \"text\": \"7one\", \"matches\": [{\"start\": 0, \"end\": 1, \"text\": \"7\", \"value\": 7}, {\"start\": 1, \"end\": 4, \"text\": \"one\", \"value\": 1}], \"first\": 0, \"last\": 1, \"value\": 71, \"no_digit\": false},\n  {\"line\": 2, \"text\": \"ab\\\"c\", \"matches\": [], \"first\": null, \"last\": null, \"value\": null, \"no_digit\": true}\n]\n"
+        );
+        assert_eq!(render(&[], Format::Json), "[]\n");
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
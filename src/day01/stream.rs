@@ -0,0 +1,100 @@
+//! Sums calibration values of documents too large to hold in memory
+
+use super::{calibration_value, find_numbers, Dictionary, Overlap};
+use anyhow::{anyhow, Context, Result};
+use rayon::prelude::*;
+use std::{io::BufRead, str};
+
+/// Bytes read at once, the chunk is extended to the end of its last line
+const CHUNK_SIZE: usize = 1 << 20;
+
+/// Sum of calibration values of every line from `reader`, the same as the solution gives
+pub fn solution_from_reader<R: BufRead + Send>(
+    reader: R,
+    numbers: &Dictionary,
+    overlap: Overlap,
+) -> Result<usize> {
+    sum_chunks(reader, numbers, overlap, CHUNK_SIZE)
+}
+
+/// Appends whole lines to `chunk` until it has at least `size` bytes or the input ends
+fn read_chunk(reader: &mut impl BufRead, chunk: &mut Vec<u8>, size: usize) -> Result<()> {
+    chunk.clear();
+    while chunk.len() < size {
+        if reader
+            .read_until(b'\n', chunk)
+            .context("Failed to read calibration document")?
+            == 0
+        {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn sum_chunk(chunk: &[u8], numbers: &Dictionary, overlap: Overlap) -> Result<usize> {
+    let text = str::from_utf8(chunk).context("Calibration document is not valid UTF-8")?;
+    text.par_split('\n')
+        .filter_map(|line| find_numbers(line, numbers, overlap))
+        .map(|(first, last)| calibration_value(first, last))
+        .try_fold(|| 0usize, |sum, value| sum.checked_add(value))
+        .try_reduce(|| 0, |left, right| left.checked_add(right))
+        .ok_or_else(|| anyhow!("Sum of calibration values overflows"))
+}
+
+/// Next chunk is read while the current one is summed
+fn sum_chunks<R: BufRead + Send>(
+    mut reader: R,
+    numbers: &Dictionary,
+    overlap: Overlap,
+    size: usize,
+) -> Result<usize> {
+    let (mut current, mut next) = (vec![], vec![]);
+    read_chunk(&mut reader, &mut current, size)?;
+    let mut sum: usize = 0;
+    while !current.is_empty() {
+        let (read, summed) = rayon::join(
+            || read_chunk(&mut reader, &mut next, size),
+            || sum_chunk(&current, numbers, overlap),
+        );
+        read?;
+        sum = sum
+            .checked_add(summed?)
+            .ok_or_else(|| anyhow!("Sum of calibration values overflows"))?;
+        std::mem::swap(&mut current, &mut next);
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01::{solution, DIGITS_DICTIONARY, SPELLED_NUMBERS_DICTIONARY};
+
+    #[test]
+    fn test_same_sum() {
+        let input = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n\nnothing\n\
+                     4nineeightseven2\nzoneight234\n7pqrstsixteen";
+        for numbers in [&*DIGITS_DICTIONARY, &*SPELLED_NUMBERS_DICTIONARY] {
+            for overlap in [Overlap::Overlapping, Overlap::Greedy] {
+                let expected = solution(input, numbers, overlap).unwrap();
+                for size in [1, 7, 40, CHUNK_SIZE] {
+                    assert_eq!(
+                        sum_chunks(input.as_bytes(), numbers, overlap, size).unwrap(),
+                        expected
+                    );
+                }
+            }
+        }
+        assert_eq!(
+            solution_from_reader(&b""[..], &DIGITS_DICTIONARY, Overlap::Overlapping).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let input: &[u8] = b"1abc2\n\xff3\n";
+        assert!(solution_from_reader(input, &DIGITS_DICTIONARY, Overlap::Overlapping).is_err());
+    }
+}
@@ -0,0 +1,235 @@
+//! Day 2: Cube Conundrum, games of drawing colored cubes out of a bag
+
+use crate::{
+    parse_error::ParseError,
+    scan,
+    solution::{Answer, Meta, Registry, Solution},
+};
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+
+const DAY: u8 = 2;
+
+/// Cubes in the bag of the puzzle
+pub const BAG: [(&str, usize); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+/// Colors multiplied into the power of a set of cubes
+pub const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// Cubes shown at once, by color name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Draw {
+    pub cubes: Vec<(String, usize)>,
+}
+
+impl Draw {
+    /// Cubes of the color, a color listed twice counts twice, saturating at `usize::MAX`
+    pub fn count(&self, color: &str) -> usize {
+        self.cubes
+            .iter()
+            .filter(|(name, _)| name == color)
+            .fold(0, |sum, (_, count)| sum.saturating_add(*count))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: usize,
+    pub draws: Vec<Draw>,
+}
+
+impl Game {
+    /// Fewest cubes of every color shown that the game could be played with
+    pub fn min_bag(&self) -> HashMap<&str, usize> {
+        let mut bag: HashMap<&str, usize> = HashMap::new();
+        for draw in self.draws.iter() {
+            for (color, _) in draw.cubes.iter() {
+                let count = bag.entry(color).or_default();
+                *count = (*count).max(draw.count(color));
+            }
+        }
+        bag
+    }
+
+    /// Whether a bag of these cubes could give every draw, colors missing from it have none
+    pub fn is_possible(&self, bag: &[(&str, usize)]) -> bool {
+        self.min_bag().into_iter().all(|(color, needed)| {
+            bag.iter()
+                .filter(|(name, _)| *name == color)
+                .fold(0usize, |sum, (_, count)| sum.saturating_add(*count))
+                >= needed
+        })
+    }
+
+    /// Product of the fewest cubes of the `colors`, zero when one of them is never shown
+    pub fn power(&self, colors: &[&str]) -> Result<usize> {
+        let bag = self.min_bag();
+        colors
+            .iter()
+            .map(|color| bag.get(color).copied().unwrap_or(0))
+            .try_fold(1usize, |power, count| power.checked_mul(count))
+            .with_context(|| format!("Power of game {} overflows", self.id))
+    }
+}
+
+fn parse_color<'a>(input: &str, color: &'a str) -> Result<&'a str, ParseError> {
+    if color.is_empty() || !color.chars().all(|ch| ch.is_alphabetic() || ch == '-') {
+        return Err(ParseError::at(DAY, input, color, "a color name"));
+    }
+    Ok(color)
+}
+
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (id, draws) = scan::header(DAY, input, line, "Game")?;
+            let draws = draws
+                .split(';')
+                .map(|draw| {
+                    let cubes = scan::counts(DAY, input, draw, "color")?
+                        .into_iter()
+                        .map(|(color, count)| Ok((parse_color(input, color)?.to_owned(), count)))
+                        .collect::<Result<_, ParseError>>()?;
+                    Ok(Draw { cubes })
+                })
+                .collect::<Result<_, ParseError>>()?;
+            Ok(Game { id, draws })
+        })
+        .collect()
+}
+
+/// Sum of ids of the games possible with the cubes of `bag`
+pub fn sum_of_possible_games(games: &[Game], bag: &[(&str, usize)]) -> usize {
+    games
+        .iter()
+        .filter(|game| game.is_possible(bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+/// Sum of powers of the smallest sets of cubes every game could be played with
+pub fn sum_of_min_required_cubes_power(games: &[Game], colors: &[&str]) -> Result<usize> {
+    games.iter().try_fold(0usize, |sum, game| {
+        sum.checked_add(game.power(colors)?)
+            .with_context(|| format!("Sum of powers overflows at game {}", game.id))
+    })
+}
+
+pub struct CubeConundrum;
+
+impl Solution for CubeConundrum {
+    type Input = Vec<Game>;
+
+    const META: Meta = Meta::new(2, "Cube Conundrum", "default", "day2_cube_conundrum");
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_games(input)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sum_of_possible_games(input, &BAG).to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sum_of_min_required_cubes_power(input, &COLORS)?.to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(CubeConundrum);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn games(input: &str) -> Vec<Game> {
+        parse_games(input).unwrap()
+    }
+
+    #[test]
+    fn test_sum_of_possible_games() {
+        let input = r#"
+            Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+            Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+        "#;
+        assert_eq!(sum_of_possible_games(&games(input), &BAG), 1 + 2 + 5);
+    }
+
+    #[test]
+    fn test_sum_of_min_cubes_power() {
+        let input = r#"
+            Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+            Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+        "#;
+        assert_eq!(
+            sum_of_min_required_cubes_power(&games(input), &COLORS).unwrap(),
+            48 + 12 + 1560 + 630 + 36
+        );
+    }
+
+    #[test]
+    fn test_power_overflow() {
+        let input = r#"
+            Game 1: 3 blue, 4 red, 2 green
+            Game 2: 4294967296 blue, 4294967296 red, 1 green
+            Game 3: 18446744073709551615 blue, 1 red, 1 green
+        "#;
+        let games = games(input);
+        let err = games[1].power(&COLORS).unwrap_err();
+        assert_eq!(err.to_string(), "Power of game 2 overflows");
+        let err = sum_of_min_required_cubes_power(&[games[0].clone(), games[2].clone()], &COLORS)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Sum of powers overflows at game 3");
+    }
+
+    #[test]
+    fn test_any_colors() {
+        let input = r#"
+            Game 1: 3 blue, 4 violet; 1 red, 2 green
+            Game 7: 2 teal, 1 red; 3 teal
+            Game 9: 1 red, 1 red, 5 green
+        "#;
+        let games = games(input);
+        assert_eq!(games[0].draws.len(), 2);
+        assert_eq!(games[0].draws[0].count("violet"), 4);
+        assert_eq!(games[1].min_bag(), HashMap::from([("teal", 3), ("red", 1)]));
+        assert_eq!(games[2].draws[0].count("red"), 2);
+
+        let bag = [("red", 2), ("green", 5), ("blue", 3), ("teal", 3)];
+        assert_eq!(sum_of_possible_games(&games, &bag), 7 + 9);
+        assert_eq!(
+            sum_of_possible_games(&games, &[("violet", 4), ("violet", 1)]),
+            0
+        );
+        let colors = ["red", "green", "blue", "violet"];
+        assert_eq!(
+            sum_of_min_required_cubes_power(&games, &colors).unwrap(),
+            24
+        );
+        assert_eq!(games[1].power(&["teal", "red"]).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_malformed_games() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue; 2 gr3y";
+        assert_eq!(
+            parse_games(input).unwrap_err(),
+            ParseError::new(2, 2, 19, "a color name", "gr3y")
+        );
+        let err = parse_games("Game one: 3 blue").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (6, "one"));
+        let err = parse_games("Game 1: 3 blue;").unwrap_err();
+        assert_eq!(err.expected, "`<count> <color>`");
+        let err = parse_games("Game 1 3 blue").unwrap_err();
+        assert_eq!(err.column, 6);
+    }
+}
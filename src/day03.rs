@@ -0,0 +1,268 @@
+//! Day 3: Gear Ratios, part numbers of an engine schematic
+
+use crate::{
+    grid::{Grid, Position},
+    parse_error::{parse_token, ParseError},
+    render::{Painter, Style},
+    scan,
+    solution::{Answer, Meta, Registry, Solution},
+};
+use anyhow::Result;
+use std::collections::HashMap;
+
+/// Schematic rows have the same width and hold digits, symbols and '.'
+pub fn parse_schematic(input: &str) -> Result<Grid<char>, ParseError> {
+    let schematic = Grid::parse_with(3, input, "a digit, a symbol or '.'", |ch| {
+        ch.is_ascii_graphic().then_some(ch)
+    })?;
+    for number in scan::unsigned(input) {
+        parse_token::<usize>(3, input, number.text, "a part number that fits a number")?;
+    }
+    Ok(schematic)
+}
+
+fn is_symbol(ch: &char) -> bool {
+    ch != &'.' && !ch.is_ascii_digit()
+}
+
+/// Number the position is a digit of, its digits are blanked so it is only read once
+///
+/// `None` as well for numbers too large for a `usize`, [`parse_schematic`] rejects those
+fn extract_number(schematic: &mut Grid<char>, (x, y): Position) -> Option<usize> {
+    let row = schematic.row(y)?;
+    if !row.get(x)?.is_ascii_digit() {
+        return None;
+    }
+    let start = row[..x]
+        .iter()
+        .rposition(|ch| !ch.is_ascii_digit())
+        .map_or(0, |i| i + 1);
+    let end = row[x..]
+        .iter()
+        .position(|ch| !ch.is_ascii_digit())
+        .map_or(row.len(), |i| x + i);
+    let number = row[start..end].iter().collect::<String>().parse().ok();
+
+    for x in start..end {
+        if let Some(cell) = schematic.get_mut((x, y)) {
+            *cell = '.';
+        }
+    }
+    number
+}
+
+fn scan_surrounds(schematic: &mut Grid<char>, position: Position) -> Vec<usize> {
+    let neighbours: Vec<Position> = schematic.neighbours8(position).collect();
+    neighbours
+        .into_iter()
+        .filter_map(|neighbour| extract_number(schematic, neighbour))
+        .collect()
+}
+
+/// Sum of numbers adjacent to a symbol, diagonals included
+pub fn sum_of_parts(schematic: &Grid<char>) -> usize {
+    let mut scan = schematic.clone();
+    schematic
+        .iter()
+        .filter(|(_, ch)| is_symbol(ch))
+        .map(|(position, _)| scan_surrounds(&mut scan, position).iter().sum::<usize>())
+        .sum()
+}
+
+/// Sum of products of the two numbers next to every `*` that has exactly two
+pub fn sum_of_gears_ratio(schematic: &Grid<char>) -> usize {
+    gears(schematic).values().sum()
+}
+
+/// `*` next to exactly two numbers with their ratio, each number is claimed by the first star that scans it
+fn gears(schematic: &Grid<char>) -> HashMap<Position, usize> {
+    let mut scan = schematic.clone();
+    schematic
+        .iter()
+        .filter(|(_, &ch)| ch == '*')
+        .map(|(position, _)| (position, scan_surrounds(&mut scan, position)))
+        .filter(|(_, parts)| parts.len() == 2)
+        .map(|(position, parts)| (position, parts.iter().product()))
+        .collect()
+}
+
+/// Schematic with part numbers, orphan numbers, gears and other symbols told apart
+pub fn render_schematic(schematic: &Grid<char>, painter: &Painter) -> String {
+    let gears = gears(schematic);
+    let next_to_symbol = |position: Position| {
+        schematic
+            .neighbours8(position)
+            .any(|neighbour| schematic.get(neighbour).is_some_and(is_symbol))
+    };
+
+    let mut out = String::new();
+    for (y, row) in schematic.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            if row[x].is_ascii_digit() {
+                let end = row[x..]
+                    .iter()
+                    .position(|ch| !ch.is_ascii_digit())
+                    .map_or(row.len(), |i| x + i);
+                let style = if (x..end).any(|x| next_to_symbol((x, y))) {
+                    Style::Part
+                } else {
+                    Style::Orphan
+                };
+                out.push_str(&painter.paint(&row[x..end].iter().collect::<String>(), style));
+                x = end;
+                continue;
+            }
+            let style = if gears.contains_key(&(x, y)) {
+                Style::Gear
+            } else if is_symbol(&row[x]) {
+                Style::Symbol
+            } else {
+                Style::Plain
+            };
+            out.push_str(&painter.paint(&row[x].to_string(), style));
+            x += 1;
+        }
+        out.push('\n');
+    }
+    out
+}
+
+pub struct GearRatios;
+
+impl Solution for GearRatios {
+    type Input = Grid<char>;
+
+    const META: Meta = Meta::new(3, "Gear Ratios", "default", "day3_gear_ratios");
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_schematic(input)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sum_of_parts(input).to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sum_of_gears_ratio(input).to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(GearRatios);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schematic(input: &str) -> Grid<char> {
+        parse_schematic(input).unwrap()
+    }
+
+    fn row(schematic: &Grid<char>) -> String {
+        schematic.row(0).unwrap().iter().collect()
+    }
+
+    #[test]
+    fn test_sum_of_parts() {
+        let input = r#"
+            467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..
+        "#;
+        assert_eq!(sum_of_parts(&schematic(input)), 4361);
+    }
+
+    #[test]
+    fn test_sum_of_gears_ratio() {
+        let input = r#"
+            467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..
+        "#;
+        assert_eq!(sum_of_gears_ratio(&schematic(input)), 467835);
+    }
+
+    #[test]
+    fn test_symbols_on_the_edge() {
+        let input = "*12\n3..\n..$\n.45";
+        assert_eq!(sum_of_parts(&schematic(input)), 12 + 3 + 45);
+        assert_eq!(sum_of_gears_ratio(&schematic(input)), 12 * 3);
+    }
+
+    #[test]
+    fn test_render_schematic() {
+        let input = "467..114..\n...*......\n..35..633.";
+        let rendered = render_schematic(&schematic(input), &Painter::plain());
+        assert_eq!(rendered, "467..114..\n...*......\n..35..633.\n");
+        let painter = Painter::new(true);
+        let colored = render_schematic(&schematic(input), &painter);
+        assert!(colored.contains(&painter.paint("467", Style::Part)));
+        assert!(colored.contains(&painter.paint("114", Style::Orphan)));
+
+        // The second star only keeps `3` once the first one has claimed `2`
+        let input = "1*2*3";
+        assert_eq!(sum_of_gears_ratio(&schematic(input)), 2);
+        let colored = render_schematic(&schematic(input), &painter);
+        assert_eq!(colored.matches(&painter.paint("*", Style::Gear)).count(), 1);
+        assert!(colored.contains(&painter.paint("*", Style::Symbol)));
+    }
+
+    #[test]
+    fn test_malformed_schematic() {
+        let err = parse_schematic("467..114..\n...*......\n..35..633").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 10));
+        assert_eq!(err.found, "end of input");
+        let err = parse_schematic("467..\n..\t*.").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert!(parse_schematic("\n  467..\n  ...*.\n").is_ok());
+        assert!(parse_schematic("").is_err());
+        let err = parse_schematic("*99999999999999999999999").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(
+                3,
+                1,
+                2,
+                "a part number that fits a number",
+                "99999999999999999999999"
+            )
+        );
+    }
+
+    #[test]
+    fn test_extract_number_start() {
+        let mut scan = schematic("123.123...123");
+        assert_eq!(extract_number(&mut scan, (0, 0)), Some(123));
+        assert_eq!(row(&scan), "....123...123");
+    }
+
+    #[test]
+    fn test_extract_number_middle() {
+        let mut scan = schematic("123.123...123");
+        assert_eq!(extract_number(&mut scan, (5, 0)), Some(123));
+        assert_eq!(row(&scan), "123.......123");
+    }
+
+    #[test]
+    fn test_extract_number_end() {
+        let mut scan = schematic("123.123...123");
+        assert_eq!(extract_number(&mut scan, (11, 0)), Some(123));
+        assert_eq!(row(&scan), "123.123......");
+    }
+}
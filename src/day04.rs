@@ -0,0 +1,176 @@
+//! Day 4: Scratchcards, winning numbers and the cards they win
+
+use crate::{
+    parse_error::ParseError,
+    scan,
+    solution::{Answer, Meta, Registry, Solution},
+};
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+
+const DAY: u8 = 4;
+
+fn get_wins_count(winning_numbers: &HashSet<usize>, numbers: &[usize]) -> usize {
+    numbers
+        .iter()
+        .filter(|num| winning_numbers.contains(num))
+        .count()
+}
+
+/// Card ids with the count of their winning numbers
+pub fn parse_cards(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (card_id, numbers) = scan::header(DAY, input, line, "Card")?;
+            let (winning, numbers) = numbers
+                .split_once('|')
+                .ok_or_else(|| ParseError::at(DAY, input, numbers, "`<winning> | <numbers>`"))?;
+            let winning_numbers = scan::numbers(DAY, input, winning, "a number")?
+                .into_iter()
+                .collect();
+            let numbers = scan::numbers(DAY, input, numbers, "a number")?;
+            Ok((card_id, get_wins_count(&winning_numbers, &numbers)))
+        })
+        .collect()
+}
+
+/// Points of every card, the first win is worth 1 and every next one doubles it
+pub fn sum_of_wins(cards: &[(usize, usize)]) -> Result<usize> {
+    let mut sum = 0usize;
+    for &(card_id, wins_count) in cards {
+        if wins_count > 0 {
+            let score = u32::try_from(wins_count - 1)
+                .ok()
+                .and_then(|pow| 2usize.checked_pow(pow))
+                .with_context(|| {
+                    format!("Card {card_id} with {wins_count} wins is worth too many points")
+                })?;
+            sum = sum
+                .checked_add(score)
+                .with_context(|| format!("Sum of points overflows at card {card_id}"))?;
+        }
+    }
+    Ok(sum)
+}
+
+/// Total count of cards once every win copies the cards below it
+pub fn sum_of_cards(cards: &[(usize, usize)]) -> Result<usize> {
+    let mut cards_counter: HashMap<usize, usize> = HashMap::new();
+    for &(card_id, wins_count) in cards {
+        let overflow = || format!("Count of cards overflows at card {card_id}");
+        let copies = cards_counter.entry(card_id).or_insert(0);
+        *copies = copies.checked_add(1).with_context(overflow)?;
+        let count = *copies;
+
+        card_id
+            .checked_add(wins_count)
+            .with_context(|| format!("Card {card_id} wins cards past the last possible id"))?;
+        for i in (1..=wins_count).map(|offset| card_id + offset) {
+            let copies = cards_counter.entry(i).or_insert(0);
+            *copies = copies.checked_add(count).with_context(overflow)?;
+        }
+    }
+    cards_counter
+        .values()
+        .try_fold(0usize, |sum, &count| sum.checked_add(count))
+        .context("Total count of cards overflows")
+}
+
+pub struct Scratchcards;
+
+impl Solution for Scratchcards {
+    type Input = Vec<(usize, usize)>;
+
+    const META: Meta = Meta::new(4, "Scratchcards", "default", "day4_scratchcards");
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_cards(input)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sum_of_wins(input)?.to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sum_of_cards(input)?.to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Scratchcards);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum_of_wins() {
+        let input = r#"
+            Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+            Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+            Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+            Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+            Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+        "#;
+        assert_eq!(sum_of_wins(&parse_cards(input).unwrap()).unwrap(), 13);
+    }
+
+    #[test]
+    fn test_points_overflow() {
+        let err = sum_of_wins(&[(1, 3), (2, 65)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Card 2 with 65 wins is worth too many points"
+        );
+        let err = sum_of_wins(&[(1, 64), (2, 64)]).unwrap_err();
+        assert_eq!(err.to_string(), "Sum of points overflows at card 2");
+    }
+
+    #[test]
+    fn test_sum_of_cards() {
+        let input = r#"
+            Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+            Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+            Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+            Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+            Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+        "#;
+        assert_eq!(sum_of_cards(&parse_cards(input).unwrap()).unwrap(), 30);
+    }
+
+    #[test]
+    fn test_cards_overflow() {
+        let input = (1..=100)
+            .map(|id| format!("Card {id}: 1 2 | 1 2"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let err = sum_of_cards(&parse_cards(&input).unwrap()).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Count of cards overflows at card"));
+        let err = sum_of_cards(&[(usize::MAX, 1)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("Card {} wins cards past the last possible id", usize::MAX)
+        );
+    }
+
+    #[test]
+    fn test_malformed_cards() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 3x | 61 30";
+        assert_eq!(
+            parse_cards(input).unwrap_err(),
+            ParseError::new(4, 2, 12, "a number", "3x")
+        );
+        let err = parse_cards("Card 1: 41 48 83 86").unwrap_err();
+        assert_eq!(err.expected, "`<winning> | <numbers>`");
+        let err = parse_cards("Crad 1: 41 | 83").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (1, "Crad"));
+    }
+}
@@ -0,0 +1,270 @@
+//! Day 5: If You Give A Seed A Fertilizer, seeds traced through almanac maps
+//!
+//! ```
+//! use advent_2023::day05::{cut_ranges, Almanac};
+//!
+//! let almanac: Almanac = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!(cut_ranges::min_location(&almanac).unwrap(), 14);
+//! ```
+
+use crate::{
+    interval::{Interval, IntervalSet, RangeMap},
+    parse_error::ParseError,
+    scan,
+    solution::{Answer, Meta, Registry, Solution},
+};
+use anyhow::{bail, Result};
+use std::str::FromStr;
+
+pub mod bruteforce;
+pub mod cut_ranges;
+pub mod path_compressed;
+
+const DAY: u8 = 5;
+const INPUT: &str = "day5_fertyseed";
+const TITLE: &str = "If You Give A Seed A Fertilizer";
+
+/// Seeds with the conversion maps in the order they are applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    /// `[destination start, source start, length]` rows of every map
+    pub maps: Vec<Vec<[usize; 3]>>,
+}
+
+impl Almanac {
+    /// Seeds read as `start length` pairs
+    pub fn seed_ranges(&self) -> Result<Vec<(usize, usize)>> {
+        if !self.seeds.len().is_multiple_of(2) {
+            bail!(
+                "Seed ranges come in start and length pairs, got {} numbers",
+                self.seeds.len()
+            );
+        }
+        Ok(self
+            .seeds
+            .chunks_exact(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect())
+    }
+
+    /// Every seed as an interval of one number
+    pub fn seed_set(&self) -> IntervalSet {
+        self.seeds
+            .iter()
+            .map(|&seed| Interval::with_len(seed, 1))
+            .collect()
+    }
+
+    pub fn seed_range_set(&self) -> Result<IntervalSet> {
+        Ok(self
+            .seed_ranges()?
+            .into_iter()
+            .map(|(start, len)| Interval::with_len(start, len))
+            .collect())
+    }
+
+    /// Maps in the order they are applied, the first row wins where rows overlap
+    pub fn range_maps(&self) -> Vec<RangeMap> {
+        self.maps
+            .iter()
+            .map(|rows| {
+                rows.iter()
+                    .map(|&[destination, source, len]| {
+                        (Interval::with_len(source, len), destination)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut sections = scan::sections(input);
+        let mut lines = sections
+            .next()
+            .into_iter()
+            .flat_map(str::lines)
+            .map(str::trim);
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(DAY, input, "`seeds: <numbers>`"))?;
+        let seeds = line
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::at(DAY, input, line, "`seeds: <numbers>`"))?;
+        let seeds: Vec<usize> = scan::numbers(DAY, input, seeds, "a seed number")?;
+        if seeds.is_empty() {
+            return Err(ParseError::at(
+                DAY,
+                input,
+                &line[line.len()..],
+                "a seed number",
+            ));
+        }
+
+        if let Some(line) = lines.next() {
+            return Err(ParseError::at(DAY, input, line, "`<from>-to-<to> map:`"));
+        }
+
+        let mut maps: Vec<Vec<[usize; 3]>> = vec![];
+        for section in sections {
+            let mut lines = section.lines().map(str::trim);
+            match lines.next() {
+                Some(header) if header.ends_with("map:") => {}
+                header => {
+                    let header = header.unwrap_or(section);
+                    return Err(ParseError::at(DAY, input, header, "`<from>-to-<to> map:`"));
+                }
+            }
+            let map = lines
+                .map(|line| {
+                    let row: Vec<usize> = scan::numbers(DAY, input, line, "a number")?;
+                    let [destination, source, len]: [usize; 3] = row.try_into().map_err(|_| {
+                        ParseError::at(DAY, input, line, "`<destination> <source> <length>`")
+                    })?;
+                    if destination.checked_add(len).is_none() || source.checked_add(len).is_none() {
+                        return Err(ParseError::at(DAY, input, line, "ranges that fit a number"));
+                    }
+                    Ok([destination, source, len])
+                })
+                .collect::<Result<_, _>>()?;
+            maps.push(map);
+        }
+        Ok(Almanac { seeds, maps })
+    }
+}
+
+pub struct Bruteforce;
+
+impl Solution for Bruteforce {
+    type Input = Almanac;
+
+    const META: Meta = Meta::new(5, TITLE, "bruteforce", INPUT).slow().reference();
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(bruteforce::min_location(input)?.to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(bruteforce::min_location_with_ranges(input)?.to_string())
+    }
+}
+
+pub struct CutRanges;
+
+impl Solution for CutRanges {
+    type Input = Almanac;
+
+    const META: Meta = Meta::new(5, TITLE, "cut_ranges", INPUT);
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(cut_ranges::min_location(input)?.to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(cut_ranges::min_location_with_ranges(input)?.to_string())
+    }
+}
+
+pub struct PathCompressed;
+
+impl Solution for PathCompressed {
+    type Input = Almanac;
+
+    const META: Meta = Meta::new(5, TITLE, "path_compressed", INPUT);
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(path_compressed::min_location(input)?.to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(path_compressed::min_location_with_ranges(input)?.to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(CutRanges);
+    registry.register(PathCompressed);
+    registry.register(Bruteforce);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_almanac() {
+        let input = r#"
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+
+        soil-to-fertilizer map:
+        0 15 37
+        "#;
+        let almanac: Almanac = input.parse().unwrap();
+        assert_eq!(almanac.seeds, vec![79, 14, 55, 13]);
+        assert_eq!(almanac.seed_ranges().unwrap(), vec![(79, 14), (55, 13)]);
+        assert_eq!(
+            almanac.maps,
+            vec![vec![[50, 98, 2], [52, 50, 48]], vec![[0, 15, 37]]]
+        );
+    }
+
+    #[test]
+    fn test_malformed_almanac() {
+        let err = "seeds: 79 14\n\nseed-to-soil map:\n50 98\n"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(5, 4, 1, "`<destination> <source> <length>`", "50")
+        );
+        let err = "seeds: 79 1x".parse::<Almanac>().unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (11, "1x"));
+        let err = "seeds:\nseed-to-soil map:".parse::<Almanac>().unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+        let err = "seeds: 1\n50 98 2".parse::<Almanac>().unwrap_err();
+        assert_eq!(err.expected, "`<from>-to-<to> map:`");
+        assert!("".parse::<Almanac>().is_err());
+
+        let input = "seeds: 1 2\n\nseed-to-soil map:\n18446744073709551615 0 10";
+        assert_eq!(
+            input.parse::<Almanac>().unwrap_err(),
+            ParseError::new(5, 4, 1, "ranges that fit a number", "18446744073709551615")
+        );
+        for solution in crate::solution::REGISTRY.variants(5) {
+            assert!(solution.parse(input).is_err());
+        }
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let almanac: Almanac = "seeds: 79 14 55".parse().unwrap();
+        assert!(almanac.seed_ranges().is_err());
+        for solution in crate::solution::REGISTRY.variants(5) {
+            let parsed = solution.parse("seeds: 79 14 55").unwrap();
+            assert_eq!(solution.part_one(&parsed).unwrap(), "14");
+            assert!(solution.part_two(&parsed).is_err());
+        }
+    }
+}
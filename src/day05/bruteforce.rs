@@ -0,0 +1,139 @@
+//! Converts every single seed through every map
+
+use super::Almanac;
+use anyhow::{anyhow, Context, Result};
+use rayon::prelude::*;
+
+/// Lowest location of the seeds
+pub fn min_location(almanac: &Almanac) -> Result<usize> {
+    let mut seeds = almanac.seeds.clone();
+    for map in almanac.range_maps() {
+        for seed in seeds.iter_mut() {
+            *seed = map.lookup(*seed);
+        }
+    }
+    seeds
+        .into_iter()
+        .min()
+        .ok_or_else(|| anyhow!("Almanac has no seeds"))
+}
+
+/// Lowest location of the seeds read as ranges
+pub fn min_location_with_ranges(almanac: &Almanac) -> Result<usize> {
+    let ranges = almanac
+        .seed_ranges()?
+        .into_iter()
+        .map(|(start, len)| {
+            let end = start.checked_add(len).with_context(|| {
+                format!("Seed range {start} {len} goes past the largest number")
+            })?;
+            Ok(start..end)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let maps = almanac.range_maps();
+
+    ranges
+        .into_par_iter()
+        .flat_map(|seeds| seeds.into_par_iter())
+        .map(|x| maps.iter().fold(x, |acc, map| map.lookup(acc)))
+        .min()
+        .ok_or_else(|| anyhow!("Almanac has no seeds"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_location() {
+        let input = r#"
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        
+        water-to-light map:
+        88 18 7
+        18 25 70
+        
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        
+        humidity-to-location map:
+        60 56 37
+        56 93 4
+        "#;
+        assert_eq!(min_location(&input.parse().unwrap()).unwrap(), 35);
+    }
+
+    #[test]
+    fn test_min_location_with_ranges() {
+        let input = r#"
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        
+        water-to-light map:
+        88 18 7
+        18 25 70
+        
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        
+        humidity-to-location map:
+        60 56 37
+        56 93 4
+        "#;
+        assert_eq!(
+            min_location_with_ranges(&input.parse().unwrap()).unwrap(),
+            46
+        );
+    }
+
+    #[test]
+    fn test_seed_range_overflow() {
+        let almanac = "seeds: 18446744073709551615 2".parse().unwrap();
+        let err = min_location_with_ranges(&almanac).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Seed range 18446744073709551615 2 goes past the largest number"
+        );
+    }
+}
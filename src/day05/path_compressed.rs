@@ -0,0 +1,125 @@
+//! Compresses all the maps into one seed-to-location map first
+
+use super::Almanac;
+use crate::interval::{IntervalSet, RangeMap};
+use anyhow::{anyhow, Result};
+use log::debug;
+
+/// Lowest location of the seeds
+pub fn min_location(almanac: &Almanac) -> Result<usize> {
+    lowest_location(almanac.seed_set(), almanac)
+}
+
+/// Lowest location of the seeds read as ranges
+pub fn min_location_with_ranges(almanac: &Almanac) -> Result<usize> {
+    lowest_location(almanac.seed_range_set()?, almanac)
+}
+
+fn lowest_location(seeds: IntervalSet, almanac: &Almanac) -> Result<usize> {
+    let compressed_map = almanac
+        .range_maps()
+        .iter()
+        .fold(RangeMap::new(), |compressed, map| compressed.compose(map));
+
+    for (source, destination) in compressed_map.ranges() {
+        debug!(
+            "{:010} -> {:010} | {:010}",
+            source.start,
+            destination,
+            source.len()
+        );
+    }
+
+    compressed_map
+        .apply_set(&seeds)
+        .min()
+        .ok_or_else(|| anyhow!("Almanac has no seeds"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_location() {
+        let input = r#"
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        
+        water-to-light map:
+        88 18 7
+        18 25 70
+        
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        
+        humidity-to-location map:
+        60 56 37
+        56 93 4
+        "#;
+        assert_eq!(min_location(&input.parse().unwrap()).unwrap(), 35);
+    }
+
+    #[test]
+    fn test_min_location_with_ranges() {
+        let input = r#"
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        
+        water-to-light map:
+        88 18 7
+        18 25 70
+        
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        
+        humidity-to-location map:
+        60 56 37
+        56 93 4
+        "#;
+        assert_eq!(
+            min_location_with_ranges(&input.parse().unwrap()).unwrap(),
+            46
+        );
+    }
+}
@@ -0,0 +1,283 @@
+//! Day 6: Wait For It, ways to beat the record of a boat race
+
+use crate::{
+    parse_error::{parse_token, ParseError},
+    scan,
+    solution::{Answer, Meta, Registry, Solution},
+};
+use anyhow::{anyhow, Context, Result};
+use log::{debug, trace};
+
+/// Holding the button for a millisecond speeds the boat up by 1 mm/ms
+#[derive(Debug)]
+pub struct Race {
+    pub time: usize,
+    pub record_distance: usize,
+}
+
+impl Race {
+    pub fn new(time: usize, record: usize) -> Self {
+        Self {
+            time,
+            record_distance: record,
+        }
+    }
+}
+
+impl Race {
+    fn find_min_time(&self, start: usize, end: usize) -> Option<usize> {
+        if start > end {
+            return None;
+        }
+        let middle = start + (end - start) / 2;
+        let distance = middle.saturating_mul(self.time - middle);
+        trace!(
+            "{:?} -> mid {} dist {} {}",
+            start..=end,
+            middle,
+            distance,
+            match self.record_distance.cmp(&distance) {
+                std::cmp::Ordering::Equal => ">",
+                std::cmp::Ordering::Greater => ">",
+                std::cmp::Ordering::Less => "<",
+            }
+        );
+        if start == end {
+            return Some(start).filter(|_| distance > self.record_distance);
+        }
+        if self.record_distance >= distance {
+            self.find_min_time(middle + 1, end)
+                .or(Some(middle).filter(|_| distance > self.record_distance))
+        } else {
+            self.find_min_time(start, middle.saturating_sub(1))
+                .or(Some(middle).filter(|_| distance > self.record_distance))
+        }
+    }
+
+    fn find_max_time(&self, start: usize, end: usize) -> Option<usize> {
+        if start > end {
+            return None;
+        }
+        let middle = start + (end - start) / 2;
+        let distance = middle.saturating_mul(self.time - middle);
+        trace!(
+            "{:?} -> mid {} dist {} {}",
+            start..=end,
+            middle,
+            distance,
+            match distance.cmp(&self.record_distance) {
+                std::cmp::Ordering::Equal => ">",
+                std::cmp::Ordering::Greater => ">",
+                std::cmp::Ordering::Less => "<",
+            }
+        );
+        if start == end {
+            return Some(start).filter(|_| distance > self.record_distance);
+        }
+        if distance >= self.record_distance {
+            self.find_max_time(middle + 1, end)
+                .or(Some(middle).filter(|_| distance > self.record_distance))
+        } else {
+            self.find_max_time(start, middle.saturating_sub(1))
+                .or(Some(middle).filter(|_| distance > self.record_distance))
+        }
+    }
+
+    /// Count of button hold times that beat the record
+    pub fn get_wins_count(&self) -> usize {
+        debug!("== {} rec {:?}", self.time, self.record_distance);
+        let min = self.find_min_time(1, self.time);
+        debug!("min {:?}", min);
+        let max = self.find_max_time(1, self.time);
+        debug!("max {:?}", max);
+
+        match (min, max) {
+            (Some(min), Some(max)) => max - min + 1,
+            _ => 0,
+        }
+    }
+}
+
+const DAY: u8 = 6;
+
+fn numbers_line<'a>(
+    input: &str,
+    lines: &mut impl Iterator<Item = &'a str>,
+    label: &str,
+) -> Result<Vec<&'a str>, ParseError> {
+    let expected = || format!("`{label} <numbers>`");
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(DAY, input, expected()))?;
+    let numbers = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::at(DAY, input, line, expected()))?;
+    scan::numbers::<usize>(DAY, input, numbers, "a number")?;
+    Ok(scan::unsigned(numbers).map(|n| n.text).collect())
+}
+
+/// Races of the sheet, along with the digits of all the times and all the distances
+#[derive(Debug)]
+pub struct Sheet {
+    pub races: Vec<Race>,
+    time_digits: String,
+    distance_digits: String,
+}
+
+impl Sheet {
+    /// Single race read with the spaces between the numbers ignored
+    pub fn smashed(&self) -> Result<Race> {
+        let smash = |digits: &str, what| {
+            digits
+                .parse::<usize>()
+                .map_err(|_| anyhow!("Smashed {what} {digits} does not fit a number"))
+        };
+        Ok(Race::new(
+            smash(&self.time_digits, "race time")?,
+            smash(&self.distance_digits, "record distance")?,
+        ))
+    }
+}
+
+pub fn parse_sheet(input: &str) -> Result<Sheet, ParseError> {
+    let (times, distances) = sheet_numbers(input)?;
+    let races = times
+        .iter()
+        .zip(distances.iter())
+        .map(|(&time, &record)| {
+            Ok(Race::new(
+                parse_token(DAY, input, time, "a race time")?,
+                parse_token(DAY, input, record, "a record distance")?,
+            ))
+        })
+        .collect::<Result<_, ParseError>>()?;
+    Ok(Sheet {
+        races,
+        time_digits: times.concat(),
+        distance_digits: distances.concat(),
+    })
+}
+
+/// Race times and record distances as they are written on the sheet
+fn sheet_numbers(input: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    let mut lines = input.lines().map(str::trim).filter(|l| !l.is_empty());
+    let times = numbers_line(input, &mut lines, "Time:")?;
+    let distances = numbers_line(input, &mut lines, "Distance:")?;
+    if let Some(extra) = distances.get(times.len()) {
+        return Err(ParseError::at(DAY, input, extra, "a distance per race"));
+    }
+    if distances.len() < times.len() {
+        let end = distances.last().map_or("", |n| &n[n.len()..]);
+        return Err(ParseError::at(DAY, input, end, "a distance per race"));
+    }
+    if let Some(line) = lines.next() {
+        return Err(ParseError::at(DAY, input, line, "end of input"));
+    }
+    Ok((times, distances))
+}
+
+/// Product of win counts of every race on the sheet
+pub fn power_of_race(sheet: &Sheet) -> Result<usize> {
+    let mut power = 1;
+    for race in sheet.races.iter() {
+        let time = race.time;
+        let wins_count = race.get_wins_count();
+        debug!("wins {:?}", wins_count);
+        power = wins_count
+            .checked_mul(power)
+            .with_context(|| format!("Power of races overflows at the {time} ms race"))?;
+    }
+    debug!("power {:?}", power);
+    Ok(power)
+}
+
+/// Win count of the single race written with badly kerned numbers
+pub fn smashed_race(sheet: &Sheet) -> Result<usize> {
+    let race = sheet.smashed()?;
+    let wins_count = race.get_wins_count();
+    debug!("wins {:?}", wins_count);
+
+    Ok(wins_count)
+}
+
+pub struct WaitRace;
+
+impl Solution for WaitRace {
+    type Input = Sheet;
+
+    const META: Meta = Meta::new(6, "Wait For It", "default", "day6_wait_race");
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_sheet(input)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(power_of_race(input)?.to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(smashed_race(input)?.to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(WaitRace);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sheet(input: &str) -> Sheet {
+        parse_sheet(input).unwrap()
+    }
+
+    #[test]
+    fn test_power_of_race() {
+        let input = r#"
+            Time:      7  15   30
+            Distance:  9  40  200
+        "#;
+        assert_eq!(power_of_race(&sheet(input)).unwrap(), 4 * 8 * 9);
+    }
+
+    #[test]
+    fn test_smashed_race() {
+        let input = r#"
+            Time:      7  15   30
+            Distance:  9  40  200
+        "#;
+        assert_eq!(smashed_race(&sheet(input)).unwrap(), 71503);
+    }
+
+    #[test]
+    fn test_unbeatable_race() {
+        assert_eq!(
+            power_of_race(&sheet("Time: 7 3\nDistance: 9 5")).unwrap(),
+            0
+        );
+        assert_eq!(smashed_race(&sheet("Time: 2\nDistance: 1")).unwrap(), 0);
+        assert!(smashed_race(&sheet("Time: 99999999999 999999999\nDistance: 1 1")).is_err());
+    }
+
+    #[test]
+    fn test_longest_race() {
+        let input = "Time: 18446744073709551615\nDistance: 0";
+        assert_eq!(power_of_race(&sheet(input)).unwrap(), usize::MAX - 1);
+        assert_eq!(smashed_race(&sheet(input)).unwrap(), usize::MAX - 1);
+    }
+
+    #[test]
+    fn test_malformed_sheet() {
+        let err = parse_sheet("Time: 7 15\nDistance: 9 4o").unwrap_err();
+        assert_eq!(err, ParseError::new(6, 2, 13, "a number", "4o"));
+        let err = parse_sheet("Time: 7 15 30\nDistance: 9 40").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 15));
+        let err = parse_sheet("Time: 7\nDistance: 9 40").unwrap_err();
+        assert_eq!(err.found, "40");
+        let err = parse_sheet("Time: 7").unwrap_err();
+        assert_eq!(err.found, "end of input");
+        let err = parse_sheet("Distance: 9\nTime: 7").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
@@ -0,0 +1,98 @@
+//! Day 7: Camel Cards, poker hands ranked by type and then card by card
+
+use crate::{
+    parse_error::{parse_token, ParseError},
+    solution::{Answer, Meta, Registry, Solution},
+};
+use anyhow::Result;
+
+pub mod joker;
+pub mod standard;
+
+const DAY: u8 = 7;
+const CARDS: &str = "23456789TJQKA";
+
+/// Hands with their bets, every hand has to be 5 known cards
+pub fn parse_hands(input: &str) -> Result<Vec<(&str, usize)>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut split = line.split_ascii_whitespace();
+            let hand = split.next().unwrap_or(line);
+            if let Some(i) = hand.find(|ch| !CARDS.contains(ch)) {
+                return Err(ParseError::at(
+                    DAY,
+                    input,
+                    &hand[i..],
+                    "a card of 23456789TJQKA",
+                ));
+            }
+            if hand.len() != 5 {
+                return Err(ParseError::at(DAY, input, hand, "a hand of 5 cards"));
+            }
+            let bet = split
+                .next()
+                .ok_or_else(|| ParseError::at(DAY, input, &line[line.len()..], "a bet"))?;
+            let bet = parse_token(DAY, input, bet, "a bet")?;
+            if let Some(extra) = split.next() {
+                return Err(ParseError::at(DAY, input, extra, "end of line"));
+            }
+            Ok((hand, bet))
+        })
+        .collect()
+}
+
+fn hands(input: &[(String, usize)]) -> impl Iterator<Item = (&str, usize)> {
+    input.iter().map(|(hand, bet)| (hand.as_str(), *bet))
+}
+
+pub struct CamelCards;
+
+impl Solution for CamelCards {
+    type Input = Vec<(String, usize)>;
+
+    const META: Meta = Meta::new(7, "Camel Cards", "default", "day7_camel_poker");
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_hands(input)?
+            .into_iter()
+            .map(|(hand, bet)| (hand.to_owned(), bet))
+            .collect())
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(standard::total_winnings(hands(input))?.to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(joker::total_winnings(hands(input))?.to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(CamelCards);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_hands() {
+        let input = "32T3K 765\nT55J5 684\nKK6X7 28";
+        assert_eq!(
+            parse_hands(input).unwrap_err(),
+            ParseError::new(7, 3, 4, "a card of 23456789TJQKA", "X7")
+        );
+        let err = parse_hands("32T3 765").unwrap_err();
+        assert_eq!(err.expected, "a hand of 5 cards");
+        let err = parse_hands("32T3K").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (6, "end of input"));
+        let err = parse_hands("32T3K 76x").unwrap_err();
+        assert_eq!(err.found, "76x");
+        let err = parse_hands("32T3K 765 1").unwrap_err();
+        assert_eq!(err.expected, "end of line");
+    }
+}
@@ -0,0 +1,163 @@
+//! Standard rules, `J` is a jack
+
+use anyhow::{anyhow, Context, Result};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+const CARDS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+static CARDS_POWER: Lazy<HashMap<char, u8>> = Lazy::new(|| {
+    let mut map = HashMap::with_capacity(CARDS.len());
+    for (i, card) in CARDS.iter().enumerate() {
+        map.insert(*card, i as u8);
+    }
+    map
+});
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Clone, Copy)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfKind,
+    FullHouse,
+    FourOfKind,
+    FiveOfKind,
+}
+
+impl TryFrom<&[u8; 5]> for HandType {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &[u8; 5]) -> Result<Self> {
+        let mut pairs = value
+            .iter()
+            .fold(HashMap::<&u8, usize>::new(), |mut map, card| {
+                map.entry(card).and_modify(|v| *v += 1).or_insert(1);
+                map
+            })
+            .values()
+            .copied()
+            .collect::<Vec<usize>>();
+        pairs.sort();
+        Ok(match &pairs[..] {
+            [5] => Self::FiveOfKind,
+            [1, 4] => Self::FourOfKind,
+            [2, 3] => Self::FullHouse,
+            [1, 1, 3] => Self::ThreeOfKind,
+            [1, 2, 2] => Self::TwoPair,
+            [1, 1, 1, 2] => Self::OnePair,
+            [1, 1, 1, 1, 1] => Self::HighCard,
+            _ => return Err(anyhow!("Cards {value:?} form no known combination")),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    /// Strength of every card, from the weakest 0 to the ace 12
+    pub hand: [u8; 5],
+    pub hand_type: HandType,
+    pub bet: usize,
+}
+
+impl TryFrom<(&str, usize)> for Hand {
+    type Error = anyhow::Error;
+
+    fn try_from((cards, bet): (&str, usize)) -> Result<Self> {
+        let hand = cards
+            .chars()
+            .map(|ch: char| {
+                CARDS_POWER
+                    .get(&ch)
+                    .copied()
+                    .ok_or_else(|| anyhow!("Unknown card {ch:?} in hand {cards}"))
+            })
+            .collect::<Result<Vec<u8>>>()?;
+        let hand: [u8; 5] = hand
+            .try_into()
+            .map_err(|_| anyhow!("Hand {cards} should have 5 cards"))?;
+
+        let hand_type = HandType::try_from(&hand)?;
+        Ok(Hand {
+            hand,
+            hand_type,
+            bet,
+        })
+    }
+}
+
+/// Sum of bets multiplied by the rank of their hand
+pub fn total_winnings<'a>(hands: impl IntoIterator<Item = (&'a str, usize)>) -> Result<usize> {
+    let mut hands = hands
+        .into_iter()
+        .enumerate()
+        .map(|(i, hand)| Hand::try_from(hand).with_context(|| format!("Hand {}", i + 1)))
+        .collect::<Result<Vec<Hand>>>()?;
+    hands.sort_by_key(|h| (h.hand_type, h.hand));
+
+    hands
+        .iter()
+        .map(|h| h.bet)
+        .enumerate()
+        .try_fold(0usize, |sum, (pow, bet)| {
+            bet.checked_mul(pow + 1)
+                .and_then(|winning| sum.checked_add(winning))
+                .with_context(|| format!("Total winnings overflow at rank {}", pow + 1))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day07::parse_hands;
+
+    #[test]
+    fn test_total_winnings() {
+        let input = r#"
+            32T3K 765
+            T55J5 684
+            KK677 28
+            KTJJT 220
+            QQQJA 483
+        "#;
+        let hands = parse_hands(input).unwrap();
+        assert_eq!(total_winnings(hands).unwrap(), 6440);
+
+        let err = total_winnings([("KK677", usize::MAX), ("32T3K", usize::MAX)]).unwrap_err();
+        assert_eq!(err.to_string(), "Total winnings overflow at rank 2");
+    }
+
+    #[test]
+    fn test_hand_type_parsing() {
+        assert_eq!(
+            HandType::try_from(&[1, 2, 3, 4, 5]).unwrap(),
+            HandType::HighCard
+        );
+        assert_eq!(
+            HandType::try_from(&[1, 1, 3, 4, 5]).unwrap(),
+            HandType::OnePair
+        );
+        assert_eq!(
+            HandType::try_from(&[1, 1, 3, 3, 5]).unwrap(),
+            HandType::TwoPair
+        );
+        assert_eq!(
+            HandType::try_from(&[1, 1, 1, 4, 5]).unwrap(),
+            HandType::ThreeOfKind
+        );
+        assert_eq!(
+            HandType::try_from(&[1, 1, 1, 4, 4]).unwrap(),
+            HandType::FullHouse
+        );
+        assert_eq!(
+            HandType::try_from(&[1, 1, 1, 1, 4]).unwrap(),
+            HandType::FourOfKind
+        );
+        assert_eq!(
+            HandType::try_from(&[1, 1, 1, 1, 1]).unwrap(),
+            HandType::FiveOfKind
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Day 8: Haunted Wasteland, following left and right turns through a network of nodes
+
+use crate::{
+    geometry::Turn,
+    graph::{find_cycle, Graph, NodeId},
+    lcm::checked_lcm,
+    parse_error::ParseError,
+    solution::{Answer, Meta, Registry, Solution},
+};
+use anyhow::{anyhow, bail, Context, Result};
+use rayon::prelude::*;
+
+const DAY: u8 = 8;
+
+/// Index of the edge to follow, edges are added left then right
+fn edge(turn: Turn) -> usize {
+    match turn {
+        Turn::Left => 0,
+        Turn::Right => 1,
+    }
+}
+
+fn parse_directions<'a>(
+    input: &str,
+    lines: &mut impl Iterator<Item = &'a str>,
+) -> Result<Vec<Turn>, ParseError> {
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(DAY, input, "`L` and `R` directions"))?;
+    line.char_indices()
+        .map(|(i, ch)| {
+            ch.try_into()
+                .map_err(|_| ParseError::at(DAY, input, &line[i..], "`L` or `R`"))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Node {
+    pub value: [u8; 3],
+    pub left: [u8; 3],
+    pub right: [u8; 3],
+}
+
+/// Node name against a pattern, `.` matches any character
+fn is_match(name: &[u8; 3], pattern: &[u8; 3]) -> bool {
+    pattern
+        .iter()
+        .zip(name)
+        .all(|(expected, ch)| expected == &b'.' || expected == ch)
+}
+
+impl Node {
+    /// Matches a node name pattern, `.` matches any character
+    pub fn is_match_value(&self, scan: &[u8; 3]) -> bool {
+        is_match(&self.value, scan)
+    }
+
+    fn code(input: &str, code: &str) -> Result<[u8; 3], ParseError> {
+        code.as_bytes()
+            .try_into()
+            .ok()
+            .filter(|code: &[u8; 3]| code.iter().all(u8::is_ascii_alphanumeric))
+            .ok_or_else(|| ParseError::at(DAY, input, code, "a 3 character node name"))
+    }
+
+    fn from_line(input: &str, line: &str) -> Result<Node, ParseError> {
+        let (source, connections) = line
+            .split_once('=')
+            .ok_or_else(|| ParseError::at(DAY, input, line, "`<node> = (<left>, <right>)`"))?;
+        let connections = connections.trim();
+        let (left, right) = connections
+            .strip_prefix('(')
+            .and_then(|c| c.strip_suffix(')'))
+            .and_then(|c| c.split_once(','))
+            .ok_or_else(|| ParseError::at(DAY, input, connections, "`(<left>, <right>)`"))?;
+        Ok(Node {
+            value: Self::code(input, source.trim())?,
+            left: Self::code(input, left.trim())?,
+            right: Self::code(input, right.trim())?,
+        })
+    }
+}
+
+/// Network of nodes by their names, every defined node has its left edge and then its right one
+#[derive(Debug, Clone)]
+pub struct Map {
+    network: Graph<[u8; 3]>,
+}
+
+impl Map {
+    fn from_input<'a>(
+        input: &str,
+        lines: impl Iterator<Item = &'a str>,
+    ) -> Result<Map, ParseError> {
+        let mut network = Graph::new();
+        for line in lines {
+            let node = Node::from_line(input, line)?;
+            network.add_edge(node.value, node.left, 1);
+            network.add_edge(node.value, node.right, 1);
+        }
+        Ok(Map { network })
+    }
+
+    fn name(&self, id: NodeId) -> std::borrow::Cow<'_, str> {
+        String::from_utf8_lossy(self.network.node(id))
+    }
+
+    /// Steps until every node matching `start` stands on a node matching `end` at the same time
+    pub fn steps_to_exit(&self, path: &[Turn], start: [u8; 3], end: [u8; 3]) -> Result<usize> {
+        if path.is_empty() {
+            bail!("No directions to follow");
+        }
+        let starts = self
+            .network
+            .nodes()
+            .filter(|&(id, name)| !self.network.edges(id).is_empty() && is_match(name, &start))
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        if starts.is_empty() {
+            bail!("No node matches {}", String::from_utf8_lossy(&start));
+        }
+        starts
+            .into_par_iter()
+            .map(|start_node| {
+                // A state is the position in the directions together with the node,
+                // the walk stops at the first exit and a cycle means it never comes
+                let mut steps = 0;
+                let mut walked = None;
+                find_cycle((0, start_node), |&(path_i, node): &(usize, NodeId)| {
+                    steps += 1;
+                    let Some(&(next, _)) = self.network.edges(node).get(edge(path[path_i])) else {
+                        walked = Some(Err(anyhow!(
+                            "Path from {} leads to an unknown node",
+                            self.name(start_node)
+                        )));
+                        return None;
+                    };
+                    if is_match(self.network.node(next), &end) {
+                        walked = Some(Ok(steps));
+                        return None;
+                    }
+                    Some(((path_i + 1) % path.len(), next))
+                });
+                walked.unwrap_or_else(|| {
+                    bail!(
+                        "Path from {} loops without reaching {}",
+                        self.name(start_node),
+                        String::from_utf8_lossy(&end)
+                    )
+                })
+            })
+            .try_reduce(
+                || 1,
+                |a, b| {
+                    checked_lcm(a, b).ok_or_else(|| anyhow!("Steps to align {a} and {b} overflow"))
+                },
+            )
+    }
+}
+
+/// Directions with the network of nodes
+pub fn parse_documents(input: &str) -> Result<(Vec<Turn>, Map), ParseError> {
+    let mut lines = input.lines().map(str::trim).filter(|l| !l.is_empty());
+    let directions = parse_directions(input, &mut lines)?;
+    let map = Map::from_input(input, lines)?;
+    Ok((directions, map))
+}
+
+/// Node pattern of 3 characters, `.` matches any character
+fn pattern(pattern: &str) -> Result<[u8; 3]> {
+    pattern
+        .as_bytes()
+        .try_into()
+        .with_context(|| format!("Node pattern {pattern:?} should be 3 characters"))
+}
+
+/// Steps from the `start` nodes to the `end` nodes, patterns as in [`Node::is_match_value`]
+pub fn min_steps(input: &str, start: &str, end: &str) -> Result<usize> {
+    let (directions, map) = parse_documents(input)?;
+    map.steps_to_exit(&directions, pattern(start)?, pattern(end)?)
+}
+
+pub struct HauntedWasteland;
+
+impl Solution for HauntedWasteland {
+    type Input = (Vec<Turn>, Map);
+
+    const META: Meta = Meta::new(8, "Haunted Wasteland", "default", "day8_haunted_wasteland");
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_documents(input)?)
+    }
+
+    fn part_one(&self, (directions, map): &Self::Input) -> Result<Answer> {
+        let steps = map.steps_to_exit(directions, pattern("AAA")?, pattern("ZZZ")?)?;
+        Ok(steps.to_string())
+    }
+
+    fn part_two(&self, (directions, map): &Self::Input) -> Result<Answer> {
+        let steps = map.steps_to_exit(directions, pattern("..A")?, pattern("..Z")?)?;
+        Ok(steps.to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(HauntedWasteland);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_steps_example_1() {
+        let input = r#"
+            RL
+
+            AAA = (BBB, CCC)
+            BBB = (DDD, EEE)
+            CCC = (ZZZ, GGG)
+            DDD = (DDD, DDD)
+            EEE = (EEE, EEE)
+            GGG = (GGG, GGG)
+            ZZZ = (ZZZ, ZZZ)
+        
+        "#;
+        assert_eq!(min_steps(input, "AAA", "ZZZ").unwrap(), 2);
+    }
+
+    #[test]
+    fn test_min_steps_example_2() {
+        let input = r#"
+            LLR
+
+            AAA = (BBB, BBB)
+            BBB = (AAA, ZZZ)
+            ZZZ = (ZZZ, ZZZ)
+        "#;
+        assert_eq!(min_steps(input, "AAA", "ZZZ").unwrap(), 6);
+    }
+    #[test]
+    fn test_min_steps_example_3() {
+        let input = r#"
+            LR
+
+            11A = (11B, XXX)
+            11B = (XXX, 11Z)
+            11Z = (11B, XXX)
+            22A = (22B, XXX)
+            22B = (22C, 22C)
+            22C = (22Z, 22Z)
+            22Z = (22B, 22B)
+            XXX = (XXX, XXX)
+        "#;
+        assert_eq!(min_steps(input, "..A", "..Z").unwrap(), 6);
+    }
+
+    #[test]
+    fn test_malformed_documents() {
+        let err = parse_documents("LRX\n\nAAA = (BBB, CCC)").unwrap_err();
+        assert_eq!(err, ParseError::new(8, 1, 3, "`L` or `R`", "X"));
+        let err = parse_documents("LR\n\nAAA = (BBB, CCC)\nBBB = (AAA CCC)").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 7));
+        let err = parse_documents("LR\n\nAAA = (BB, CCC)").unwrap_err();
+        assert_eq!(err.found, "BB");
+        let err = parse_documents("LR\n\nAAA (BBB, CCC)").unwrap_err();
+        assert_eq!(err.expected, "`<node> = (<left>, <right>)`");
+    }
+
+    #[test]
+    fn test_unreachable_exit() {
+        let input = "L\n\nAAA = (BBB, BBB)\nBBB = (BBB, BBB)\nZZZ = (ZZZ, ZZZ)";
+        assert!(min_steps(input, "AAA", "ZZZ").is_err());
+        assert!(min_steps(input, "CCC", "ZZZ").is_err());
+        assert!(min_steps(input, "AAAA", "ZZZ").is_err());
+        assert!(min_steps("L\n\nAAA = (CCC, CCC)", "AAA", "ZZZ").is_err());
+    }
+
+    #[test]
+    fn test_exit_before_unknown_node() {
+        let input = "L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (QQQ, QQQ)";
+        assert_eq!(min_steps(input, "AAA", "ZZZ").unwrap(), 1);
+    }
+}
@@ -0,0 +1,97 @@
+//! Day 9: Mirage Maintenance, extrapolating histories of readings
+
+use crate::{
+    parse_error::ParseError,
+    scan,
+    sequence::Polynomial,
+    solution::{Answer, Meta, Registry, Solution},
+};
+use anyhow::{anyhow, Result};
+use rayon::prelude::*;
+
+/// One history of readings per line
+pub fn parse_histories(input: &str) -> Result<Vec<Vec<isize>>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| scan::numbers(9, input, line, "a number"))
+        .collect()
+}
+
+/// Sums of the previous and the next values of every history
+pub fn extrapolation_sum(histories: &[Vec<isize>]) -> Result<(isize, isize)> {
+    histories
+        .par_iter()
+        .map(|history| {
+            let overflow = || anyhow!("Extrapolating {history:?} overflows");
+            let polynomial = Polynomial::checked_fit(history).ok_or_else(overflow)?;
+            let prev = polynomial.checked_at(-1).ok_or_else(overflow)?;
+            let next = polynomial
+                .checked_at(history.len() as isize)
+                .ok_or_else(overflow)?;
+            Ok((prev, next))
+        })
+        .try_reduce(
+            || (0, 0),
+            |(prev_sum, next_sum), (prev, next)| {
+                prev_sum
+                    .checked_add(prev)
+                    .zip(next_sum.checked_add(next))
+                    .ok_or_else(|| anyhow!("Sum of extrapolated values overflows"))
+            },
+        )
+}
+
+pub struct Mirage;
+
+impl Solution for Mirage {
+    type Input = Vec<Vec<isize>>;
+
+    const META: Meta = Meta::new(9, "Mirage Maintenance", "default", "day9_mirage");
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_histories(input)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(extrapolation_sum(input)?.1.to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(extrapolation_sum(input)?.0.to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Mirage);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_1() {
+        let input = r#"
+            0 3 6 9 12 15
+            1 3 6 10 15 21
+            10 13 16 21 30 45
+        "#;
+        let histories = parse_histories(input).unwrap();
+        assert_eq!(extrapolation_sum(&histories).unwrap(), (2, 114));
+    }
+
+    #[test]
+    fn test_malformed_history() {
+        let err = parse_histories("0 3 6\n1 3 - 10").unwrap_err();
+        assert_eq!(err, ParseError::new(9, 2, 5, "a number", "-"));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = isize::MAX;
+        assert!(extrapolation_sum(&[vec![0, max]]).is_err());
+        assert!(extrapolation_sum(&[vec![max, max], vec![1, 1]]).is_err());
+    }
+}
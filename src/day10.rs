@@ -0,0 +1,100 @@
+//! Day 10: Pipe Maze, the loop of pipes through the starting point and the tiles it encloses
+
+use crate::{
+    parse_error::{check_grid, ParseError},
+    solution::{Answer, Meta, Registry, Solution},
+};
+use anyhow::Result;
+
+pub mod flood_fill;
+pub mod scanline;
+
+const INPUT: &str = "day10_maze";
+const TITLE: &str = "Pipe Maze";
+
+/// Maze is a rectangle of pipes and ground with a single starting point
+pub fn check_maze(input: &str) -> Result<(), ParseError> {
+    check_grid(
+        10,
+        input,
+        |ch| "|-LJ7F.S".contains(ch),
+        "a pipe, '.' or 'S'",
+    )?;
+    let mut starts = input.match_indices('S').map(|(i, _)| &input[i..]);
+    match (starts.next(), starts.next()) {
+        (None, _) => Err(ParseError::end_of_input(10, input, "a starting point 'S'")),
+        (Some(_), Some(second)) => Err(ParseError::at(10, input, second, "a single 'S'")),
+        (Some(_), None) => Ok(()),
+    }
+}
+
+pub struct FloodFill;
+
+impl Solution for FloodFill {
+    type Input = flood_fill::Maze;
+
+    const META: Meta = Meta::new(10, TITLE, "flood_fill", INPUT).reference();
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        check_maze(input)?;
+        input.parse()
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(flood_fill::solve_maze(input)?.0.to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(flood_fill::solve_maze(input)?.1.to_string())
+    }
+}
+
+pub struct Scanline;
+
+impl Solution for Scanline {
+    type Input = scanline::PipeMaze;
+
+    const META: Meta = Meta::new(10, TITLE, "scanline", INPUT);
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        check_maze(input)?;
+        scanline::parse_input(input.to_string())
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(scanline::solve_part1(input).to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(scanline::solve_part2(input).to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(FloodFill);
+    registry.register(Scanline);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_maze() {
+        assert!(check_maze(".....\n.S-7.\n.|.|.\n.L-J.\n.....").is_ok());
+        let err = check_maze(".....\n.S-7.\n.|x|.").unwrap_err();
+        assert_eq!(err, ParseError::new(10, 3, 3, "a pipe, '.' or 'S'", "x|."));
+        let err = check_maze(".F-7.\n.|.|.\n.L-J.").unwrap_err();
+        assert_eq!(err.expected, "a starting point 'S'");
+        let err = check_maze(".S-7.\n.|.|.\n.L-S.").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 4));
+        assert!(Scanline.parse(".S-7.\n.|.|").is_err());
+    }
+
+    #[test]
+    fn test_pipes_off_the_edge() {
+        let maze = Scanline.parse("S--\n|..").unwrap();
+        assert_eq!(Scanline.part_one(&maze).unwrap(), "2");
+        assert!(Scanline.part_two(&maze).is_ok());
+    }
+}
@@ -0,0 +1,393 @@
+//! Walks the loop, then floods the outside of a maze scaled up three times
+
+use crate::{
+    geometry::{Direction, Point},
+    graph::{dfs, find_cycle},
+    grid::{Grid, Position},
+    render::{Painter, Style},
+};
+use anyhow::{anyhow, bail, Error, Result};
+use log::debug;
+use std::{
+    collections::HashSet,
+    fmt::{Display, Write},
+    iter,
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MazeMark {
+    NorthSouth,
+    EastWest,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    NoPipe,
+    Start,
+    Seen,
+}
+
+impl MazeMark {
+    fn exits(&self) -> &'static [Direction] {
+        use Direction::*;
+        match self {
+            Self::NorthSouth => &[North, South],
+            Self::EastWest => &[East, West],
+            Self::NorthEast => &[North, East],
+            Self::NorthWest => &[North, West],
+            Self::SouthWest => &[South, West],
+            Self::SouthEast => &[South, East],
+            _ => &[],
+        }
+    }
+
+    fn from_exits(exits: &[Direction]) -> Self {
+        [
+            Self::NorthSouth,
+            Self::EastWest,
+            Self::NorthEast,
+            Self::NorthWest,
+            Self::SouthWest,
+            Self::SouthEast,
+        ]
+        .into_iter()
+        .find(|mark| mark.exits().iter().all(|exit| exits.contains(exit)))
+        .unwrap_or(Self::NoPipe)
+    }
+
+    fn next_position(&self, prev: Position, pos: Position) -> Option<Position> {
+        self.exits()
+            .iter()
+            .filter_map(|&exit| step(pos, exit))
+            .find(|&next| next != prev)
+    }
+
+    /// Whether the pipe has an exit back towards where it is entered from
+    fn can_enter(&self, from: Direction) -> bool {
+        self.exits().contains(&from.opposite())
+    }
+}
+
+fn step(position: Position, direction: Direction) -> Option<Position> {
+    Point::from(position)
+        .checked_step(direction)
+        .map(Into::into)
+}
+
+impl From<char> for MazeMark {
+    fn from(ch: char) -> Self {
+        match ch {
+            '|' => Self::NorthSouth,
+            '-' => Self::EastWest,
+            'L' => Self::NorthEast,
+            'J' => Self::NorthWest,
+            '7' => Self::SouthWest,
+            'F' => Self::SouthEast,
+            'S' => Self::Start,
+            '*' => Self::Seen,
+            _ => Self::NoPipe,
+        }
+    }
+}
+
+impl Display for MazeMark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NorthSouth => f.write_char('|'),
+            Self::EastWest => f.write_char('-'),
+            Self::NorthEast => f.write_char('L'),
+            Self::NorthWest => f.write_char('J'),
+            Self::SouthWest => f.write_char('7'),
+            Self::SouthEast => f.write_char('F'),
+            Self::Start => f.write_char('S'),
+            Self::Seen => f.write_char('*'),
+            Self::NoPipe => f.write_char('.'),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Maze {
+    map: Grid<MazeMark>,
+}
+
+impl FromStr for Maze {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let map = Grid::parse_with(10, s, "a maze tile", |ch| {
+            (!ch.is_whitespace()).then(|| MazeMark::from(ch))
+        })?;
+        Ok(Self { map })
+    }
+}
+
+impl Display for Maze {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("map\n")?;
+        self.map.fmt(f)
+    }
+}
+
+impl Maze {
+    pub fn get(&self, position: Position) -> Option<&MazeMark> {
+        self.map.get(position)
+    }
+
+    fn change(&mut self, position: Position, val: MazeMark) -> Result<()> {
+        let mark = self
+            .map
+            .get_mut(position)
+            .ok_or_else(|| anyhow!("Tried access undefined value"))?;
+        *mark = val;
+        Ok(())
+    }
+}
+
+impl Maze {
+    pub fn find_start(&self) -> Option<Position> {
+        self.map.position(|mark| mark == &MazeMark::Start)
+    }
+
+    fn start_exits(&self, start: Position) -> Vec<Direction> {
+        Direction::CARDINAL
+            .into_iter()
+            .filter(|&exit| {
+                self.map
+                    .step(start, exit)
+                    .and_then(|pos| self.get(pos))
+                    .is_some_and(|mark| mark.can_enter(exit))
+            })
+            .collect()
+    }
+
+    fn find_next_from_start(&self, start: Position) -> Option<Position> {
+        self.start_exits(start)
+            .into_iter()
+            .find_map(|exit| step(start, exit))
+    }
+}
+
+impl Maze {
+    /// Positions of the loop going through the start
+    pub fn walk_main_loop(&self) -> Result<HashSet<Position>> {
+        let start = self
+            .find_start()
+            .ok_or_else(|| anyhow!("No starting point found"))?;
+        let start_mark = MazeMark::from_exits(&self.start_exits(start));
+        let first = self
+            .find_next_from_start(start)
+            .ok_or_else(|| anyhow!("No path from start"))?;
+
+        // A step remembers where it came from, so every pipe has a single way forward
+        let step = |&(prev, pos): &(Position, Position)| {
+            let mark = if pos == start {
+                &start_mark
+            } else {
+                self.get(pos)?
+            };
+            mark.next_position(prev, pos).map(|next| (pos, next))
+        };
+        let cycle = find_cycle((start, first), step)
+            .filter(|cycle| cycle.start == 0)
+            .ok_or_else(|| anyhow!("Main loop through {start:?} is broken"))?;
+        Ok(iter::successors(Some((start, first)), step)
+            .take(cycle.length)
+            .map(|(prev, _)| prev)
+            .collect())
+    }
+
+    fn clear_junk(&mut self, main_loop: &HashSet<Position>) -> Result<()> {
+        let start = self
+            .find_start()
+            .ok_or_else(|| anyhow!("No starting point found"))?;
+        let start_mark = MazeMark::from_exits(&self.start_exits(start));
+        for (position, mark) in self.map.iter_mut() {
+            if !main_loop.contains(&position) {
+                *mark = MazeMark::NoPipe;
+            }
+        }
+        self.change(start, start_mark)
+    }
+
+    // Every tile becomes 3x3 block, so the gaps between pipes are walkable
+    // .|.   ...   .|.
+    // .|.   ---   .L-
+    // .|.   ...   ...
+    fn expand(&self) -> Maze {
+        let map = Grid::from_fn(self.map.width() * 3, self.map.height() * 3, |(x, y)| {
+            let mark = self.map.get((x / 3, y / 3)).unwrap_or(&MazeMark::NoPipe);
+            let delta = ((x % 3) as isize - 1, (y % 3) as isize - 1);
+            match Direction::from_delta(delta) {
+                None if mark != &MazeMark::NoPipe => *mark,
+                Some(exit) if mark.exits().contains(&exit) => {
+                    if exit.is_vertical() {
+                        MazeMark::NorthSouth
+                    } else {
+                        MazeMark::EastWest
+                    }
+                }
+                _ => MazeMark::NoPipe,
+            }
+        });
+        Maze { map }
+    }
+
+    /// Floods the ground reachable from the edges of the expanded maze
+    fn see_outside(&mut self) -> Result<()> {
+        let expanded = self.expand();
+        let y_len = expanded.map.height();
+        let x_len = expanded.map.width();
+        if x_len == 0 || y_len == 0 {
+            bail!("Map is empty");
+        }
+        let edges = (0..y_len)
+            .flat_map(|y| [(0, y), (x_len - 1, y)])
+            .chain((1..x_len - 1).flat_map(|x| [(x, 0), (x, y_len - 1)]));
+        let is_ground = |pos: &Position| expanded.get(*pos) == Some(&MazeMark::NoPipe);
+        let outside: HashSet<Position> = dfs(edges.filter(is_ground), |&pos| {
+            expanded.map.neighbours4(pos).filter(is_ground)
+        })
+        .into_iter()
+        .collect();
+
+        for ((x, y), mark) in self.map.iter_mut() {
+            if outside.contains(&(x * 3 + 1, y * 3 + 1)) {
+                *mark = MazeMark::Seen;
+            }
+        }
+        Ok(())
+    }
+
+    fn count_no_pipes(&self) -> usize {
+        self.map
+            .iter()
+            .filter(|(_, mark)| mark == &&MazeMark::NoPipe)
+            .count()
+    }
+}
+
+/// Farthest distance along the loop and the count of tiles it encloses
+pub fn solve_maze(maze: &Maze) -> Result<(usize, usize)> {
+    let mut maze = maze.clone();
+    debug!("{maze}");
+    let main_loop = maze.walk_main_loop()?;
+    let distance = main_loop.len() / 2;
+    maze.clear_junk(&main_loop)?;
+    maze.see_outside()?;
+    debug!("{maze}");
+    let enclosed_area = maze.count_no_pipes();
+    Ok((distance, enclosed_area))
+}
+
+/// Maze with the pipes of the loop, the enclosed `I` tiles and the outside `O` tiles told apart
+pub fn render_maze(maze: &Maze, painter: &Painter) -> Result<String> {
+    let mut maze = maze.clone();
+    let pipes = maze.map.clone();
+    let main_loop = maze.walk_main_loop()?;
+    maze.clear_junk(&main_loop)?;
+    maze.see_outside()?;
+
+    let mut out = String::new();
+    for (position, mark) in pipes.iter() {
+        let rendered = if main_loop.contains(&position) {
+            painter.paint(&mark.to_string(), Style::Path)
+        } else if maze.get(position) == Some(&MazeMark::Seen) {
+            painter.paint("O", Style::Outside)
+        } else {
+            painter.paint("I", Style::Inside)
+        };
+        out.push_str(&rendered);
+        if position.0 + 1 == pipes.width() {
+            out.push('\n');
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_maze() {
+        let input = r#"
+            -L|F7
+            7S-7|
+            L|7||
+            -L-J|
+            L|-JF
+        "#;
+        let rendered = render_maze(&input.parse().unwrap(), &Painter::plain()).unwrap();
+        assert_eq!(rendered, "OOOOO\nOS-7O\nO|I|O\nOL-JO\nOOOOO\n");
+    }
+
+    #[test]
+    fn test_example_1() {
+        let input = r#"
+            .....
+            .S-7.
+            .|.|.
+            .L-J.
+            .....
+        "#;
+        assert_eq!(solve_maze(&input.parse().unwrap()).unwrap(), (4, 1));
+    }
+
+    #[test]
+    fn test_example_1_1() {
+        let input = r#"
+            .....
+            .F-7.
+            .S.|.
+            .L-J.
+            .....
+        "#;
+        assert_eq!(solve_maze(&input.parse().unwrap()).unwrap(), (4, 1));
+    }
+
+    #[test]
+    fn test_example_2() {
+        let input = r#"
+            ..F7.
+            .FJ|.
+            SJ.L7
+            |F--J
+            LJ...
+        "#;
+        assert_eq!(solve_maze(&input.parse().unwrap()).unwrap(), (8, 1));
+    }
+
+    #[test]
+    fn test_example_3() {
+        let input = r#"
+            ...........
+            .S-------7.
+            .|F-----7|.
+            .||.....||.
+            .||.....||.
+            .|L-7.F-J|.
+            .|..|.|..|.
+            .L--J.L--J.
+            ...........
+        "#;
+        assert_eq!(solve_maze(&input.parse().unwrap()).unwrap(), (23, 4));
+    }
+
+    #[test]
+    fn test_example_4() {
+        let input = r#"
+            OF----7F7F7F7F-7OOOO
+            O|F--7||||||||FJOOOO
+            O||OFJ||||||||L7OOOO
+            FJL7L7LJLJ||LJIL-7OO
+            L--JOL7IIILJS7F-7L7O
+            OOOOF-JIIF7FJ|L7L7L7
+            OOOOL7IF7||L7|IL7L7|
+            OOOOO|FJLJ|FJ|F7|OLJ
+            OOOOFJL-7O||O||||OOO
+            OOOOL---JOLJOLJLJOOO
+        "#;
+        assert_eq!(solve_maze(&input.parse().unwrap()).unwrap(), (70, 8));
+    }
+}
@@ -0,0 +1,194 @@
+// PipeMaze
+// https://adventofcode.com/2023/day/10
+// https://www.youtube.com/watch?v=N4obLWVZ3FM
+
+use crate::{
+    geometry::Direction,
+    graph::bfs,
+    grid::{Grid, Position},
+};
+use anyhow::{Context, Result};
+use log::debug;
+use std::{
+    collections::HashSet,
+    fmt::{Display, Write},
+    ops::Range,
+};
+
+type InputType = PipeMaze;
+type MazeType = Grid<char>;
+pub type SolutionType = i32;
+
+#[derive(Debug, PartialEq)]
+pub struct PipeMaze {
+    maze: MazeType,
+    start: Position,
+}
+
+/// Pipes drawn with box characters
+impl Display for PipeMaze {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.maze.rows() {
+            for c in row {
+                f.write_char(match c {
+                    'L' => '╚',
+                    'J' => '╝',
+                    '7' => '╗',
+                    'F' => '╔',
+                    '|' => '║',
+                    '-' => '═',
+                    _ => *c,
+                })?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+/// Maze with the start replaced by the pipe under it
+pub fn parse_input(input_str: String) -> Result<InputType> {
+    let mut maze: MazeType = input_str.parse()?;
+    let start = maze.position(|&c| c == 'S').context("No start found")?;
+    let shape = start_shape(&maze, start);
+    if let Some(cell) = maze.get_mut(start) {
+        *cell = shape;
+    }
+    Ok(PipeMaze { maze, start })
+}
+
+/// Pipe hidden under the 'S', so the scanline can count it as a wall or a corner
+fn start_shape(maze: &MazeType, start: Position) -> char {
+    let exits = starting_exits(maze, start);
+    PIPES
+        .into_iter()
+        .find(|&pipe| pipe_exits(pipe).iter().all(|exit| exits.contains(exit)))
+        .unwrap_or('S')
+}
+
+const PIPES: [char; 6] = ['|', 'L', 'J', 'F', '7', '-'];
+
+fn pipe_exits(c: char) -> &'static [Direction] {
+    use Direction::*;
+    match c {
+        '|' => &[North, South],
+        '-' => &[East, West],
+        'L' => &[North, East],
+        'J' => &[North, West],
+        '7' => &[South, West],
+        'F' => &[South, East],
+        _ => &[],
+    }
+}
+
+/// Directions from the start to the pipes that lead back into it
+fn starting_exits(maze: &MazeType, start: Position) -> Vec<Direction> {
+    Direction::CARDINAL
+        .into_iter()
+        .filter(|&exit| {
+            maze.step(start, exit)
+                .and_then(|position| maze.get(position))
+                .is_some_and(|&cell| pipe_exits(cell).contains(&exit.opposite()))
+        })
+        .collect()
+}
+
+fn get_starting_connections(maze: &MazeType, start: Position) -> Vec<Position> {
+    starting_exits(maze, start)
+        .into_iter()
+        .filter_map(|exit| maze.step(start, exit))
+        .collect()
+}
+
+/// Tiles the pipe at `position` leads to, pipes pointing off the edge lead nowhere
+fn get_connections(maze: &MazeType, position: Position) -> Vec<Position> {
+    let Some(&cell) = maze.get(position) else {
+        return vec![];
+    };
+    pipe_exits(cell)
+        .iter()
+        .filter_map(|&exit| maze.step(position, exit))
+        .collect()
+}
+
+fn find_path_length(maze: &MazeType, start: Position) -> (SolutionType, HashSet<Position>) {
+    let distances = bfs([start], |&position| {
+        if position == start {
+            get_starting_connections(maze, start)
+        } else {
+            get_connections(maze, position)
+        }
+    });
+    let farthest = distances.values().max().copied().unwrap_or_default();
+    (farthest as SolutionType, distances.into_keys().collect())
+}
+
+/// Farthest distance along the loop
+pub fn solve_part1(input: &InputType) -> SolutionType {
+    find_path_length(&input.maze, input.start).0
+}
+
+fn find_bounding_rect(path: &HashSet<Position>) -> (Range<usize>, Range<usize>) {
+    let (minx, maxx, miny, maxy) = path.iter().fold(
+        (usize::MAX, 0, usize::MAX, 0),
+        |(minx, maxx, miny, maxy), (x, y)| (minx.min(*x), maxx.max(*x), miny.min(*y), maxy.max(*y)),
+    );
+    (minx..maxx + 1, miny..maxy + 1)
+}
+
+fn find_points_inside(
+    path: &HashSet<Position>,
+    row: &[char],
+    xrange: Range<usize>,
+    y: usize,
+) -> SolutionType {
+    // 'S' is replaced with its pipe when parsing the input.
+    let walls: HashSet<char> = ['L', 'J', '7', 'F', '|'].iter().cloned().collect();
+    let corners: HashSet<char> = ['L', 'J', '7', 'F'].iter().cloned().collect();
+
+    let mut count = 0;
+    let mut inside = false;
+    let mut prev_corner: Option<char> = None;
+    for x in xrange {
+        if path.contains(&(x, y)) {
+            let cell = row[x];
+            if walls.contains(&cell) {
+                if corners.contains(&cell) {
+                    if let Some(prev) = prev_corner {
+                        // These corners extend the vertical edge we've already accounted for.
+                        if (prev == 'L' && cell == '7') || (prev == 'F' && cell == 'J') {
+                            prev_corner = None;
+                            continue;
+                        }
+                    }
+                    prev_corner = Some(cell);
+                }
+                inside = !inside;
+            }
+            continue;
+        }
+        if inside {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Count of tiles enclosed by the loop, crossing its walls row by row
+pub fn solve_part2(input: &InputType) -> SolutionType {
+    let (_, path) = find_path_length(&input.maze, input.start);
+    let (range_x, range_y) = find_bounding_rect(&path);
+    debug!("{input}");
+
+    range_y
+        .clone()
+        .filter_map(|y| {
+            Some(find_points_inside(
+                &path,
+                input.maze.row(y)?,
+                range_x.clone(),
+                y,
+            ))
+        })
+        .sum()
+}
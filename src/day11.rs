@@ -0,0 +1,302 @@
+//! Day 11: Cosmic Expansion, distances between galaxies of an expanding universe
+
+use crate::{
+    geometry::Point,
+    grid::Grid,
+    parse_error::ParseError,
+    render::{Painter, Style},
+    solution::{Answer, Meta, Registry, Solution},
+};
+use anyhow::Result;
+use log::debug;
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+/// Galaxy at its column and row
+pub type Galaxy = Point;
+
+/// Image of the sky with the rows and columns that have no galaxies
+#[derive(Debug)]
+pub struct StarMap {
+    map: Grid<char>,
+    galaxies: Vec<Galaxy>,
+    empty_rows: HashSet<usize>,
+    empty_cols: HashSet<usize>,
+}
+
+impl StarMap {
+    pub fn galaxies(&self) -> &[Galaxy] {
+        &self.galaxies
+    }
+
+    /// Every pair of galaxies once
+    pub fn pair_galaxies(&self) -> Vec<(Galaxy, Galaxy)> {
+        let mut res = vec![];
+        for i in 0..self.galaxies.len() {
+            for j in i + 1..self.galaxies.len() {
+                res.push((self.galaxies[i], self.galaxies[j]))
+            }
+        }
+        res
+    }
+
+    fn emptiness_between_cols(&self, first: usize, second: usize, expansion: usize) -> usize {
+        let mut max = first;
+        let mut min = second;
+        if min > max {
+            std::mem::swap(&mut max, &mut min);
+        }
+        let count = self
+            .empty_cols
+            .iter()
+            .filter(|&i| i > &min && i < &max)
+            .count();
+        max - min - count + count * expansion
+    }
+
+    fn emptiness_between_rows(&self, first: usize, second: usize, expansion: usize) -> usize {
+        let mut max = first;
+        let mut min = second;
+        if min > max {
+            std::mem::swap(&mut max, &mut min);
+        }
+        let count = self
+            .empty_rows
+            .iter()
+            .filter(|&i| i > &min && i < &max)
+            .count();
+        max - min - count + count * expansion
+    }
+
+    /// Map with the empty rows and columns marked and every galaxy shown by its number
+    pub fn render(&self, painter: &Painter) -> String {
+        let width = self.map.width();
+        let mut cells: Vec<Vec<(char, Style)>> = (0..self.map.height())
+            .map(|y| {
+                (0..width)
+                    .map(|x| {
+                        let empty = self.empty_rows.contains(&y) || self.empty_cols.contains(&x);
+                        ('.', if empty { Style::Empty } else { Style::Plain })
+                    })
+                    .collect()
+            })
+            .collect();
+        // Numbers are written over the cells that follow the galaxy
+        for (i, galaxy) in self.galaxies.iter().enumerate() {
+            let row = &mut cells[galaxy.y];
+            for (x, digit) in (galaxy.x..width).zip((i + 1).to_string().chars()) {
+                row[x] = (digit, Style::Label);
+            }
+        }
+
+        let markers = |marker: &str| -> String {
+            (0..width)
+                .map(|x| {
+                    if self.empty_cols.contains(&x) {
+                        painter.paint(marker, Style::Empty)
+                    } else {
+                        " ".to_owned()
+                    }
+                })
+                .collect()
+        };
+        let mut out = format!(" {}\n", markers("v"));
+        for (y, row) in cells.into_iter().enumerate() {
+            let empty = self.empty_rows.contains(&y);
+            if empty {
+                out.push_str(&painter.paint(">", Style::Empty));
+            } else {
+                out.push(' ');
+            }
+            for (ch, style) in row {
+                out.push_str(&painter.paint(&ch.to_string(), style));
+            }
+            if empty {
+                out.push_str(&painter.paint("<", Style::Empty));
+            }
+            out.push('\n');
+        }
+        out.push_str(&format!(" {}\n", markers("^")));
+        out
+    }
+
+    /// Shortest path between galaxies with every empty row and column `expansion` times wider
+    pub fn distance(&self, left: &Galaxy, right: &Galaxy, expansion: usize) -> usize {
+        self.emptiness_between_cols(left.x, right.x, expansion)
+            + self.emptiness_between_rows(left.y, right.y, expansion)
+    }
+}
+
+impl FromStr for StarMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse_with(11, s, "'.' or '#'", |ch| {
+            matches!(ch, '.' | '#').then_some(ch)
+        })?;
+        let empty_rows: HashSet<usize> = map
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| row.iter().all(|ch| ch == &'.'))
+            .map(|(i, _)| i)
+            .collect();
+        let empty_cols: HashSet<usize> = map
+            .columns()
+            .enumerate()
+            .filter_map(|(i, mut col)| col.all(|ch| ch == &'.').then_some(i))
+            .collect();
+
+        let galaxies: Vec<Galaxy> = map
+            .iter()
+            .filter(|(_, ch)| ch == &&'#')
+            .map(|(position, _)| Galaxy::from(position))
+            .collect();
+        Ok(Self {
+            map,
+            galaxies,
+            empty_cols,
+            empty_rows,
+        })
+    }
+}
+
+impl Display for StarMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(' ')?;
+        for i in 0..self.map.width() {
+            if self.empty_cols.contains(&i) {
+                f.write_char('v')?;
+            } else {
+                f.write_char(' ')?;
+            }
+        }
+        f.write_char('\n')?;
+        let mut start_count = 0;
+        for (i, row) in self.map.rows().enumerate() {
+            if self.empty_rows.contains(&i) {
+                f.write_char('>')?;
+            } else {
+                f.write_char(' ')?;
+            }
+            let mut star_num = VecDeque::new();
+            for ch in row.iter() {
+                if ch == &'#' {
+                    start_count += 1;
+                    star_num.clear();
+                    star_num.extend(start_count.to_string().chars());
+                }
+                if let Some(ch) = star_num.pop_front() {
+                    f.write_char(ch)?;
+                } else {
+                    f.write_char(*ch)?;
+                }
+            }
+            if self.empty_rows.contains(&i) {
+                f.write_char('<')?;
+            }
+            f.write_char('\n')?;
+        }
+        f.write_char(' ')?;
+        for i in 0..self.map.width() {
+            if self.empty_cols.contains(&i) {
+                f.write_char('^')?;
+            } else {
+                f.write_char(' ')?;
+            }
+        }
+        f.write_char('\n')?;
+        f.write_fmt(format_args!(
+            "cols {:?} rows {:?}",
+            self.empty_cols, self.empty_rows
+        ))?;
+        f.write_char('\n')?;
+        for (i, g) in self.galaxies.iter().enumerate() {
+            f.write_fmt(format_args!("{:3} -> ({:3}, {:3})\n", i + 1, g.x, g.y))?;
+        }
+        Ok(())
+    }
+}
+
+/// Sums of distances between all pairs of galaxies, one per expansion
+pub fn sum_closest_pairs(map: &StarMap, expansions: Vec<usize>) -> Vec<usize> {
+    debug!("{map}");
+    let galaxy_pairs = map.pair_galaxies();
+
+    expansions
+        .into_iter()
+        .map(|expansion| {
+            galaxy_pairs
+                .iter()
+                .map(|(left, right)| map.distance(left, right, expansion))
+                .sum()
+        })
+        .collect()
+}
+
+pub struct CosmicExpansion;
+
+impl Solution for CosmicExpansion {
+    type Input = StarMap;
+
+    const META: Meta = Meta::new(11, "Cosmic Expansion", "default", "day11_cosmic_expansion");
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(StarMap::from_str(input)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sum_closest_pairs(input, vec![2])[0].to_string())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sum_closest_pairs(input, vec![1_000_000])[0].to_string())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(CosmicExpansion);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_1() {
+        let input = r#"
+            ...#......
+            .......#..
+            #.........
+            ..........
+            ......#...
+            .#........
+            .........#
+            ..........
+            .......#..
+            #...#.....
+        "#;
+        assert_eq!(
+            sum_closest_pairs(&StarMap::from_str(input).unwrap(), vec![2, 10, 100]),
+            vec![374, 1030, 8410]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let map = StarMap::from_str("#..\n...\n..#").unwrap();
+        assert_eq!(
+            map.render(&Painter::plain()),
+            "  v \n 1..\n>...<\n ..2\n  ^ \n"
+        );
+    }
+
+    #[test]
+    fn test_malformed_map() {
+        let err = StarMap::from_str("...#\n.o..").unwrap_err();
+        assert_eq!(err, ParseError::new(11, 2, 2, "'.' or '#'", "o.."));
+        assert!(StarMap::from_str("...#\n...").is_err());
+    }
+}
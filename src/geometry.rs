@@ -0,0 +1,296 @@
+use anyhow::{bail, Error, Result};
+use std::ops::{Add, Sub};
+
+/// Point on a grid, `x` goes along a row and `y` down the rows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T = usize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Point<usize> {
+    /// `None` when the step goes below zero
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.delta();
+        Some(Self::new(
+            self.x.checked_add_signed(dx)?,
+            self.y.checked_add_signed(dy)?,
+        ))
+    }
+
+    /// `None` when the step leaves the `width` by `height` rectangle
+    pub fn step_within(self, direction: Direction, width: usize, height: usize) -> Option<Self> {
+        self.checked_step(direction)
+            .filter(|point| point.x < width && point.y < height)
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn signed(self) -> Option<Point<isize>> {
+        Some(Point::new(self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl Point<isize> {
+    /// `None` when the step leaves the `isize` range
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.delta();
+        Some(Self::new(self.x.checked_add(dx)?, self.y.checked_add(dy)?))
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// `None` when the point is left or above the origin
+    pub fn unsigned(self) -> Option<Point<usize>> {
+        Some(Point::new(self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+/// Compass direction on a grid where north is up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// Clockwise from the north
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+    /// Clockwise from the north
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// `(dx, dy)` of one step, `y` grows to the south
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    pub fn from_delta(delta: (isize, isize)) -> Option<Direction> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.delta() == delta)
+    }
+
+    /// Turns clockwise by `eighths` of a full turn, negative ones turn counterclockwise
+    pub fn rotate(self, eighths: isize) -> Direction {
+        let index = Self::ALL
+            .iter()
+            .position(|&d| d == self)
+            .unwrap_or_default();
+        Self::ALL[(index as isize + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-2)
+    }
+
+    pub fn turn(self, turn: Turn) -> Direction {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        Self::CARDINAL.contains(&self)
+    }
+
+    /// North or south
+    pub fn is_vertical(self) -> bool {
+        self.delta().0 == 0
+    }
+}
+
+/// Point facing a direction, it moves forward and turns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Heading {
+    pub position: Point<isize>,
+    pub direction: Direction,
+}
+
+impl Heading {
+    pub fn new(position: Point<isize>, direction: Direction) -> Self {
+        Self {
+            position,
+            direction,
+        }
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        Self::new(self.position, self.direction.turn(turn))
+    }
+
+    /// One step in the facing direction, `None` when it leaves the `isize` range
+    pub fn forward(self) -> Option<Self> {
+        Some(Self::new(
+            self.position.checked_step(self.direction)?,
+            self.direction,
+        ))
+    }
+}
+
+/// Turn relative to the current [`Heading`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Turn {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            _ => bail!("Unexpected input"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthWest.turn(Turn::Right), Direction::NorthEast);
+        assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+        assert_eq!(Direction::West.rotate(-9), Direction::SouthWest);
+        assert_eq!(Direction::from_delta((1, 1)), Some(Direction::SouthEast));
+        assert_eq!(Direction::from_delta((0, 0)), None);
+        for direction in Direction::ALL {
+            let (dx, dy) = direction.delta();
+            assert_eq!(direction.opposite().delta(), (-dx, -dy));
+        }
+        assert!(Direction::South.is_vertical() && !Direction::SouthEast.is_vertical());
+        assert!(!Direction::SouthEast.is_cardinal());
+    }
+
+    #[test]
+    fn test_point() {
+        let origin = Point::new(0usize, 0);
+        assert_eq!(origin.checked_step(Direction::North), None);
+        assert_eq!(
+            origin.checked_step(Direction::SouthEast),
+            Some(Point::new(1, 1))
+        );
+        assert_eq!(origin.step_within(Direction::East, 1, 1), None);
+        assert_eq!(
+            Point::new(0, 1).step_within(Direction::North, 1, 2),
+            Some(origin)
+        );
+        assert_eq!(Point::new(1usize, 6).manhattan(Point::new(5, 11)), 9);
+
+        let signed = Point::new(2isize, -3);
+        assert_eq!(
+            signed.checked_step(Direction::NorthWest),
+            Some(Point::new(1, -4))
+        );
+        assert_eq!(
+            signed.checked_step(Direction::South),
+            Some(Point::new(2, -2))
+        );
+        assert_eq!(
+            Point::new(isize::MAX, 0).checked_step(Direction::East),
+            None
+        );
+        assert_eq!(
+            Point::new(0, isize::MIN).checked_step(Direction::North),
+            None
+        );
+        assert_eq!(signed.unsigned(), None);
+        assert_eq!(signed + Point::new(1, 3), Point::new(3, 0));
+        assert_eq!(Point::new(3usize, 4).signed(), Some(Point::new(3, 4)));
+        assert_eq!(<(usize, usize)>::from(Point::new(3, 4)), (3, 4));
+    }
+
+    #[test]
+    fn test_heading() {
+        let heading = Heading::new(Point::new(0, 0), Direction::North);
+        let moved = heading
+            .forward()
+            .and_then(|h| h.turn(Turn::Right).forward())
+            .unwrap();
+        assert_eq!(moved, Heading::new(Point::new(1, -1), Direction::East));
+        assert_eq!(moved.turn(Turn::Left).direction, Direction::North);
+        let edge = Heading::new(Point::new(0, isize::MIN), Direction::North);
+        assert_eq!(edge.forward(), None);
+    }
+}
@@ -0,0 +1,302 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Index of a node interned in a [`Graph`]
+pub type NodeId = usize;
+
+/// Directed graph with weighted edges, node values are interned into dense ids
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    ids: HashMap<N, NodeId>,
+    nodes: Vec<N>,
+    edges: Vec<Vec<(NodeId, usize)>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            nodes: vec![],
+            edges: vec![],
+        }
+    }
+}
+
+impl<N: Eq + Hash + Clone> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Id of `node`, added without edges when it is new
+    pub fn intern(&mut self, node: N) -> NodeId {
+        match self.ids.entry(node) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                let id = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                self.edges.push(vec![]);
+                entry.insert(id);
+                id
+            }
+        }
+    }
+
+    pub fn id(&self, node: &N) -> Option<NodeId> {
+        self.ids.get(node).copied()
+    }
+
+    /// Panics when the id does not come from this graph
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Nodes in the order they were interned
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> {
+        self.nodes.iter().enumerate()
+    }
+
+    /// Edge going one way, both nodes are interned when they are new
+    pub fn add_edge(&mut self, from: N, to: N, weight: usize) -> (NodeId, NodeId) {
+        let from = self.intern(from);
+        let to = self.intern(to);
+        self.edges[from].push((to, weight));
+        (from, to)
+    }
+
+    /// Outgoing edges with their weights, in the order they were added
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, usize)] {
+        self.edges.get(id).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn neighbours(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges(id).iter().map(|&(next, _)| next)
+    }
+
+    /// Weakly connected components, edges count in both directions
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        let mut undirected = vec![vec![]; self.len()];
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, _) in edges {
+                undirected[from].push(to);
+                undirected[to].push(from);
+            }
+        }
+        connected_components(0..self.len(), |&id| undirected[id].clone())
+    }
+}
+
+/// Fewest edges from any of `starts` to every node reachable from them
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = distances.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Nodes reachable from `starts`, in depth-first order
+pub fn dfs<N, I>(starts: impl IntoIterator<Item = N>, mut successors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut order = vec![];
+    let mut stack = starts.into_iter().collect::<Vec<_>>();
+    stack.reverse();
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        let next = successors(&node)
+            .into_iter()
+            .filter(|next| !seen.contains(next))
+            .collect::<Vec<_>>();
+        stack.extend(next.into_iter().rev());
+        order.push(node);
+    }
+    order
+}
+
+/// Lowest total weight from `start` to every node reachable from it
+pub fn dijkstra<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut distances = HashMap::new();
+    // The heap orders indices into `pending`, so nodes do not have to be `Ord`
+    let mut pending = vec![start];
+    let mut heap = BinaryHeap::from([(Reverse(0usize), 0)]);
+    while let Some((Reverse(cost), i)) = heap.pop() {
+        let node = pending[i].clone();
+        let Entry::Vacant(entry) = distances.entry(node.clone()) else {
+            continue;
+        };
+        entry.insert(cost);
+        for (next, weight) in successors(&node) {
+            if !distances.contains_key(&next) {
+                heap.push((Reverse(cost.saturating_add(weight)), pending.len()));
+                pending.push(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Groups of nodes linked to each other, `successors` has to go both ways
+pub fn connected_components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Vec<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut components = vec![];
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let component = dfs([node], &mut successors);
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+    components
+}
+
+/// Where a walk starts repeating itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps taken before entering the cycle
+    pub start: usize,
+    pub length: usize,
+}
+
+/// Cycle reached by following `next` from `start`, `None` when the walk stops first
+pub fn find_cycle<N>(start: N, mut next: impl FnMut(&N) -> Option<N>) -> Option<Cycle>
+where
+    N: Eq + Hash + Clone,
+{
+    let mut seen = HashMap::new();
+    let mut node = start;
+    let mut step = 0;
+    loop {
+        if let Some(&first) = seen.get(&node) {
+            return Some(Cycle {
+                start: first,
+                length: step - first,
+            });
+        }
+        let following = next(&node)?;
+        seen.insert(node, step);
+        node = following;
+        step += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        for (from, to, weight) in [
+            ("a", "b", 7),
+            ("a", "c", 2),
+            ("c", "b", 3),
+            ("b", "d", 1),
+            ("e", "f", 1),
+        ] {
+            graph.add_edge(from, to, weight);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_interning() {
+        let mut graph = graph();
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph.id(&"c"), Some(2));
+        assert_eq!(graph.intern("c"), 2);
+        assert_eq!(graph.intern("g"), 6);
+        assert_eq!(graph.node(1), &"b");
+        assert_eq!(graph.neighbours(0).collect::<Vec<_>>(), vec![1, 2]);
+        assert!(graph.edges(6).is_empty());
+        assert!(graph.edges(100).is_empty());
+    }
+
+    #[test]
+    fn test_search() {
+        let graph = graph();
+        let distances = bfs([0], |&id| graph.neighbours(id));
+        assert_eq!(distances.len(), 4);
+        assert_eq!(distances[&3], 2);
+        assert_eq!(dfs([0], |&id| graph.neighbours(id)), vec![0, 1, 3, 2]);
+        let costs = dijkstra(0, |&id| graph.edges(id).to_vec());
+        assert_eq!((costs[&1], costs[&3]), (5, 6));
+        assert!(!costs.contains_key(&4));
+    }
+
+    #[test]
+    fn test_components() {
+        let mut components = graph().components();
+        components.iter_mut().for_each(|c| c.sort());
+        assert_eq!(components, vec![vec![0, 1, 2, 3], vec![4, 5]]);
+        let parity = connected_components(0..6, |&n: &usize| [n ^ 2, (n + 4) % 8]);
+        assert_eq!(parity.len(), 2);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let next = |&n: &usize| Some((n * n + 1) % 10);
+        // 3 -> 0 -> 1 -> 2 -> 5 -> 6 -> 7 -> 0
+        assert_eq!(
+            find_cycle(3, next),
+            Some(Cycle {
+                start: 1,
+                length: 6
+            })
+        );
+        assert_eq!(
+            find_cycle(0, |&n: &usize| Some((n + 1) % 4)),
+            Some(Cycle {
+                start: 0,
+                length: 4
+            })
+        );
+        assert_eq!(find_cycle(0, |&n: &usize| (n < 5).then_some(n + 1)), None);
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{
+    geometry::Direction,
+    parse_error::{check_grid, ParseError},
+};
+use std::{
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+/// `(x, y)` position, `x` goes along a row and `y` down the rows
+pub type Position = (usize, usize);
+
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Rectangle of cells stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(width, height, |_| cell.clone())
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses non-blank lines with `cell`, pointing at the first rejected character or ragged row
+    pub fn parse_with(
+        day: u8,
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        check_grid(day, input, |ch| cell(ch).is_some(), expected)?;
+        let rows = input.lines().map(str::trim).filter(|l| !l.is_empty());
+        let height = rows.clone().count();
+        let cells: Vec<T> = rows.flat_map(|row| row.chars()).filter_map(cell).collect();
+        Ok(Self {
+            width: cells.len() / height,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, (x, y): Position) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index(position).map(|i| &mut self.cells[i])
+    }
+
+    /// Moves by `(dx, dy)`, `None` when it leaves the grid
+    pub fn shift(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let position = x.checked_add_signed(dx).zip(y.checked_add_signed(dy))?;
+        self.index(position).map(|_| position)
+    }
+
+    /// One step in `direction`, `None` when it leaves the grid
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        self.shift(position, direction.delta())
+    }
+
+    /// Orthogonal neighbours inside the grid, clockwise from the north
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid, row by row
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |delta| self.shift(position, delta))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).filter_map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).filter_map(|x| self.column(x))
+    }
+
+    /// Cells with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// First position, row by row, of a cell matching the predicate
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Rows become columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self.cells[x * self.width + y].clone()
+        })
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(0, s, "a grid cell", |ch| T::try_from(ch).ok())
+    }
+}
+
+/// Cells are expected to be one character wide
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (scale, from) in [(100, 100), (10, 10), (1, 0)] {
+            if self.width <= from && from > 0 {
+                continue;
+            }
+            f.write_str("   ")?;
+            for x in 0..self.width {
+                f.write_char(' ')?;
+                if x < from {
+                    f.write_char(' ')?;
+                } else {
+                    f.write_str(&(x / scale % 10).to_string())?;
+                }
+            }
+            if scale > 1 {
+                f.write_char('\n')?;
+            }
+        }
+        f.write_str(" x\n")?;
+        for (y, row) in self.rows().enumerate() {
+            f.write_fmt(format_args!("{:3}", &y.to_string()))?;
+            for cell in row.iter() {
+                f.write_char(' ')?;
+                f.write_str(&cell.to_string())?;
+            }
+            f.write_char('\n')?;
+        }
+        f.write_str("y\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<char> = "\n  ab\n  cd\n  ef\n".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.get((1, 2)), Some(&'f'));
+        assert_eq!(grid.get((2, 0)), None);
+        let err = "abc\nde".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "a row of 3 cells");
+        let err = Grid::parse_with(11, ".#\n.x", "'.' or '#'", |ch| {
+            matches!(ch, '.' | '#').then_some(ch)
+        })
+        .unwrap_err();
+        assert_eq!(err, ParseError::new(11, 2, 2, "'.' or '#'", "x"));
+
+        let err = "éa\nabc".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(err.expected, "a row of 2 cells");
+        let grid: Grid<char> = "éa\nbc".parse().unwrap();
+        assert_eq!((grid.width(), grid.get((0, 0))), (2, Some(&'é')));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8((2, 1)).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 0), (1, 1), (1, 2), (2, 2)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = Grid::from_fn(3, 2, |(x, y)| x + y * 3);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![[0, 1, 2], [3, 4, 5]]);
+        let columns = grid
+            .columns()
+            .map(|c| c.copied().collect())
+            .collect::<Vec<Vec<_>>>();
+        assert_eq!(columns, vec![vec![0, 3], vec![1, 4], vec![2, 5]]);
+        assert!(grid.column(3).is_none());
+        let transposed = grid.transpose();
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed.row(2), Some(&[2, 5][..]));
+        *grid.get_mut((2, 1)).unwrap() = 9;
+        assert_eq!(grid.position(|&cell| cell == 9), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_display() {
+        let grid: Grid<char> = "S-7\nL-J".parse().unwrap();
+        assert_eq!(grid.to_string(), "    0 1 2 x\n0   S - 7\n1   L - J\ny\n");
+        let wide = Grid::new(12, 1, '.');
+        let lines = wide.to_string();
+        let mut lines = lines.lines();
+        assert_eq!(lines.next(), Some(format!("{:24}1 1", "").as_str()));
+        assert_eq!(lines.next(), Some("    0 1 2 3 4 5 6 7 8 9 0 1 x"));
+    }
+}
@@ -0,0 +1,346 @@
+use std::ops::Range;
+
+/// Half-open `start..end` range of numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Interval {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// `len` numbers from `start`
+    pub fn with_len(start: usize, len: usize) -> Self {
+        Self::new(start, start.saturating_add(len))
+    }
+
+    pub fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, number: usize) -> bool {
+        self.start <= number && number < self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Common part, `None` when they do not overlap
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let common = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!common.is_empty()).then_some(common)
+    }
+}
+
+impl From<Range<usize>> for Interval {
+    fn from(range: Range<usize>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+/// Sorted disjoint intervals, the ones that overlap or touch are merged
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let from = self.intervals.partition_point(|i| i.end < interval.start);
+        let to = self.intervals.partition_point(|i| i.start <= interval.end);
+        let mut merged = interval;
+        if from < to {
+            merged.start = merged.start.min(self.intervals[from].start);
+            merged.end = merged.end.max(self.intervals[to - 1].end);
+        }
+        self.intervals.splice(from..to, [merged]);
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Count of numbers in the set
+    pub fn size(&self) -> usize {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn min(&self) -> Option<usize> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn contains(&self, number: usize) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= number);
+        self.intervals.get(i).is_some_and(|i| i.contains(number))
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for interval in other.iter() {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (left, right) = (&self.intervals, &other.intervals);
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < left.len() && j < right.len() {
+            if let Some(common) = left[i].intersection(&right[j]) {
+                intervals.push(common);
+            }
+            if left[i].end < right[j].end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// Numbers of `self` that are not in `other`
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = vec![];
+        for interval in self.iter() {
+            let mut start = interval.start;
+            for cut in other.iter().filter(|cut| cut.overlaps(&interval)) {
+                if cut.start > start {
+                    intervals.push(Interval::new(start, cut.start));
+                }
+                start = start.max(cut.end);
+            }
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+/// Moves the numbers of source intervals next to destination starts, other numbers stay the same
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    /// Sorted disjoint sources with their destination starts
+    ranges: Vec<(Interval, usize)>,
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parts of `source` that are already mapped keep their earlier destination
+    ///
+    /// `destination + source.len()` has to fit a `usize`
+    pub fn insert(&mut self, source: Interval, destination: usize) {
+        let mapped: IntervalSet = self.ranges.iter().map(|&(source, _)| source).collect();
+        let unmapped = IntervalSet::from_iter([source]).difference(&mapped);
+        for part in unmapped.iter() {
+            self.ranges
+                .push((part, destination + (part.start - source.start)));
+        }
+        self.ranges.sort_by_key(|(source, _)| source.start);
+    }
+
+    /// Mapped sources with their destination starts, sorted by source
+    pub fn ranges(&self) -> &[(Interval, usize)] {
+        &self.ranges
+    }
+
+    pub fn lookup(&self, number: usize) -> usize {
+        let i = self
+            .ranges
+            .partition_point(|(source, _)| source.end <= number);
+        match self.ranges.get(i) {
+            Some((source, destination)) if source.contains(number) => {
+                destination + (number - source.start)
+            }
+            _ => number,
+        }
+    }
+
+    /// `interval` cut where the ranges start and end, each part with its destination start
+    fn segments(&self, interval: Interval) -> Vec<(Interval, usize)> {
+        let mut segments = vec![];
+        let mut start = interval.start;
+        for (source, destination) in self.ranges.iter() {
+            let Some(common) = source.intersection(&interval) else {
+                continue;
+            };
+            if common.start > start {
+                segments.push((Interval::new(start, common.start), start));
+            }
+            segments.push((common, destination + (common.start - source.start)));
+            start = common.end;
+        }
+        if start < interval.end {
+            segments.push((Interval::new(start, interval.end), start));
+        }
+        segments
+    }
+
+    /// Where the numbers of `interval` end up, split wherever the ranges split it
+    pub fn apply(&self, interval: Interval) -> IntervalSet {
+        self.apply_set(&IntervalSet::from_iter([interval]))
+    }
+
+    pub fn apply_set(&self, set: &IntervalSet) -> IntervalSet {
+        set.iter()
+            .flat_map(|interval| self.segments(interval))
+            .map(|(part, destination)| Interval::with_len(destination, part.len()))
+            .collect()
+    }
+
+    /// Single map doing `self` first and `next` after it
+    pub fn compose(&self, next: &RangeMap) -> RangeMap {
+        let mut ranges: Vec<(Interval, usize)> = vec![];
+        for (source, destination) in self.segments(Interval::new(0, usize::MAX)) {
+            let image = Interval::with_len(destination, source.len());
+            for (part, target) in next.segments(image) {
+                let start = source.start + (part.start - destination);
+                if start == target {
+                    continue;
+                }
+                match ranges.last_mut() {
+                    Some((last, last_target))
+                        if last.end == start && *last_target + last.len() == target =>
+                    {
+                        last.end += part.len();
+                    }
+                    _ => ranges.push((Interval::with_len(start, part.len()), target)),
+                }
+            }
+        }
+        RangeMap { ranges }
+    }
+}
+
+impl FromIterator<(Interval, usize)> for RangeMap {
+    fn from_iter<T: IntoIterator<Item = (Interval, usize)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        for (source, destination) in iter {
+            map.insert(source, destination);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[Range<usize>]) -> IntervalSet {
+        intervals.iter().cloned().map(Interval::from).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::with_len(3, 4);
+        assert_eq!(interval, Interval::new(3, 7));
+        assert_eq!(interval.len(), 4);
+        assert!(interval.contains(6) && !interval.contains(7));
+        assert_eq!(
+            interval.intersection(&(5..10).into()),
+            Some(Interval::new(5, 7))
+        );
+        assert_eq!(interval.intersection(&(7..10).into()), None);
+        assert!(Interval::new(4, 2).is_empty());
+        assert_eq!(Interval::new(4, 2).len(), 0);
+    }
+
+    #[test]
+    fn test_coalescing() {
+        let set = set(&[5..7, 0..2, 2..3, 9..9, 6..8]);
+        assert_eq!(set.intervals(), &[(0..3).into(), (5..8).into()]);
+        assert_eq!(set.size(), 6);
+        assert_eq!(set.min(), Some(0));
+        assert!(set.contains(7) && !set.contains(8) && !set.contains(3));
+        let mut set = set;
+        set.insert((1..6).into());
+        assert_eq!(set.intervals(), &[(0..8).into()]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let left = set(&[0..5, 10..15]);
+        let right = set(&[3..12, 20..25]);
+        assert_eq!(left.union(&right), set(&[0..15, 20..25]));
+        assert_eq!(left.intersection(&right), set(&[3..5, 10..12]));
+        assert_eq!(left.difference(&right), set(&[0..3, 12..15]));
+        assert_eq!(right.difference(&left), set(&[5..10, 20..25]));
+        assert!(left.difference(&left).is_empty());
+    }
+
+    #[test]
+    fn test_range_map() {
+        let map: RangeMap = [((98..100).into(), 50), ((50..98).into(), 52)]
+            .into_iter()
+            .collect();
+        assert_eq!(map.lookup(79), 81);
+        assert_eq!(map.lookup(99), 51);
+        assert_eq!(map.lookup(10), 10);
+        assert_eq!(
+            map.apply((95..102).into()),
+            set(&[50..52, 97..100, 100..102])
+        );
+
+        let overlapping: RangeMap = [((0..10).into(), 100), ((5..15).into(), 200)]
+            .into_iter()
+            .collect();
+        assert_eq!(overlapping.lookup(7), 107);
+        assert_eq!(overlapping.lookup(12), 207);
+    }
+
+    #[test]
+    fn test_compose() {
+        let first: RangeMap = [((98..100).into(), 50), ((50..98).into(), 52)]
+            .into_iter()
+            .collect();
+        let second: RangeMap = [
+            ((15..52).into(), 0),
+            ((52..54).into(), 37),
+            ((0..15).into(), 39),
+        ]
+        .into_iter()
+        .collect();
+        let composed = first.compose(&second);
+        for number in 0..120 {
+            assert_eq!(composed.lookup(number), second.lookup(first.lookup(number)));
+        }
+        assert_eq!(RangeMap::new().compose(&RangeMap::new()), RangeMap::new());
+    }
+}
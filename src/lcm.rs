@@ -1,21 +1,261 @@
-pub fn lcm(first: usize, second: usize) -> usize {
-    first * second / gcd(first, second)
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// Primitive integers the number theory helpers work with
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+    fn wrapping_rem(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
 }
 
-fn gcd(first: usize, second: usize) -> usize {
+/// Integers with a sign, needed for Bézout coefficients
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+macro_rules! integer {
+    ($abs:expr; $($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_abs(self) -> Option<Self> {
+                $abs(self)
+            }
+
+            fn wrapping_rem(self, rhs: Self) -> Self {
+                <$t>::wrapping_rem(self, rhs)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+        }
+    )*};
+}
+
+integer!(Some; u8, u16, u32, u64, u128, usize);
+integer!(|n: Self| n.checked_abs(); i8, i16, i32, i64, i128, isize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+/// Greatest common divisor, never negative, `None` when it does not fit like `gcd(i32::MIN, 0)`
+pub fn checked_gcd<T: Integer>(first: T, second: T) -> Option<T> {
     let mut max = first;
     let mut min = second;
-    if min > max {
-        std::mem::swap(&mut max, &mut min);
+    while min != T::ZERO {
+        (max, min) = (min, max.wrapping_rem(min));
+    }
+    max.checked_abs()
+}
+
+/// Greatest common divisor, `gcd(0, 0)` is 0
+///
+/// Panics when the result does not fit into `T`
+pub fn gcd<T: Integer>(first: T, second: T) -> T {
+    checked_gcd(first, second)
+        .unwrap_or_else(|| panic!("gcd of {first:?} and {second:?} overflows"))
+}
+
+/// Least common multiple, never negative, `None` when it does not fit into `T`
+pub fn checked_lcm<T: Integer>(first: T, second: T) -> Option<T> {
+    if first == T::ZERO || second == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (first / checked_gcd(first, second)?)
+        .checked_mul(second)?
+        .checked_abs()
+}
+
+/// Least common multiple, divides before multiplying so only a result that does not fit overflows
+///
+/// Panics when the result does not fit into `T`
+pub fn lcm<T: Integer>(first: T, second: T) -> T {
+    checked_lcm(first, second)
+        .unwrap_or_else(|| panic!("lcm of {first:?} and {second:?} overflows"))
+}
+
+/// Greatest common divisor of all numbers, 0 for none
+pub fn checked_gcd_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().try_fold(T::ZERO, checked_gcd)
+}
+
+pub fn gcd_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ZERO, gcd)
+}
+
+/// Least common multiple of all numbers, 1 for none
+pub fn checked_lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().try_fold(T::ONE, checked_lcm)
+}
+
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// `(gcd, x, y)` with `first * x + second * y == gcd`
+pub fn extended_gcd<T: Signed>(first: T, second: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (first, second);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
     }
+}
+
+/// `x` in `0..modulus` with `number * x ≡ 1`, `None` when they are not coprime
+pub fn mod_inverse<T: Signed>(number: T, modulus: T) -> Option<T> {
+    if modulus <= T::ZERO {
+        return None;
+    }
+    let (gcd, x, _) = extended_gcd(number.rem_euclid(modulus), modulus);
+    (gcd == T::ONE).then(|| x.rem_euclid(modulus))
+}
 
-    loop {
-        let res = max % min;
-        if res == 0 {
-            return min;
+/// `first * second % modulus` without overflowing, `None` when `modulus` takes more than half of `T`
+pub fn checked_mul_mod<T: Integer>(first: T, second: T, modulus: T) -> Option<T> {
+    if modulus <= T::ZERO {
+        return None;
+    }
+    let two = T::ONE + T::ONE;
+    let mut first = first.rem_euclid(modulus);
+    let mut second = second.rem_euclid(modulus);
+    let mut result = T::ZERO;
+    while second > T::ZERO {
+        if second % two == T::ONE {
+            result = result.checked_add(first)? % modulus;
         }
+        first = first.checked_add(first)? % modulus;
+        second = second / two;
+    }
+    Some(result)
+}
+
+/// Chinese remainder theorem for `(residue, modulus)` pairs, the moduli do not have to be coprime
+///
+/// Gives the smallest non-negative solution with the lcm of the moduli, `None` when the
+/// congruences contradict each other, a modulus is not positive or the lcm does not fit
+pub fn crt<T: Signed>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences.into_iter().try_fold(
+        (T::ZERO, T::ONE),
+        |(residue, modulus), (next, next_modulus)| {
+            if next_modulus <= T::ZERO {
+                return None;
+            }
+            let next = next.rem_euclid(next_modulus);
+            let gcd = checked_gcd(modulus, next_modulus)?;
+            let diff = next.checked_sub(residue)?;
+            if diff.wrapping_rem(gcd) != T::ZERO {
+                return None;
+            }
+            let step = next_modulus / gcd;
+            let inverse = mod_inverse(modulus / gcd, step)?;
+            let k = checked_mul_mod(diff / gcd, inverse, step)?;
+            let lcm = (modulus / gcd).checked_mul(next_modulus)?;
+            Some((modulus.checked_mul(k)?.checked_add(residue)?, lcm))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12usize, 18), 6);
+        assert_eq!(gcd(-12i32, 18), 6);
+        assert_eq!(gcd(0u8, 5), 5);
+        assert_eq!(gcd(5u8, 0), 5);
+        assert_eq!(gcd(0u8, 0), 0);
+        assert_eq!(checked_gcd(i32::MIN, 0), None);
+        assert_eq!(checked_gcd(i32::MIN, -1), Some(1));
+        assert_eq!(gcd_all([12u32, 18, 30]), 6);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4usize, 6), 12);
+        assert_eq!(lcm(-4i64, 6), 12);
+        assert_eq!(lcm(0u8, 6), 0);
+        assert_eq!(checked_lcm(120u8, 60), Some(120));
+        assert_eq!(checked_lcm(200u8, 3), None);
+        assert_eq!(lcm_all([2u64, 3, 4]), 12);
+        assert_eq!(lcm_all(Vec::<u64>::new()), 1);
+        assert_eq!(checked_lcm_all([u64::MAX, 2]), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(240i64, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(-240i64, 46), (2, 9, 47));
+        assert_eq!(extended_gcd(0i64, 0), (0, 1, 0));
+        assert_eq!(mod_inverse(3i32, 11), Some(4));
+        assert_eq!(mod_inverse(-3i32, 11), Some(7));
+        assert_eq!(mod_inverse(2i32, 4), None);
+        assert_eq!(mod_inverse(2i32, 0), None);
+    }
+
+    #[test]
+    fn test_mul_mod() {
+        assert_eq!(checked_mul_mod(7u8, 9, 10), Some(3));
+        assert_eq!(checked_mul_mod(100u8, 100, 101), Some(1));
+        assert_eq!(checked_mul_mod(-1i8, 5, 7), Some(2));
+        assert_eq!(checked_mul_mod(1u8, 1, 0), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(2i64, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(-2i64, 4), (16, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt([(1i64, 0)]), None);
+        assert_eq!(crt(Vec::<(i64, i64)>::new()), Some((0, 1)));
 
-        max = min;
-        min = res;
+        let (m1, m2) = (999_999_999_989i128, 999_999_999_959i128);
+        let (x, lcm) = crt([(1, m1), (2, m2)]).unwrap();
+        assert_eq!((x % m1, x % m2, lcm), (1, 2, m1 * m2));
+        assert_eq!(crt([(1i64, m1 as i64), (2, m2 as i64)]), None);
     }
 }
@@ -1,2 +1,28 @@
+pub mod answers;
+pub mod bench;
+pub mod compare;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod interval;
 pub mod lcm;
+pub mod logging;
 pub mod number_trie;
+pub mod parse_error;
+pub mod puzzle_input;
+pub mod render;
+pub mod scan;
+pub mod sequence;
+pub mod solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
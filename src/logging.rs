@@ -0,0 +1,116 @@
+use anyhow::{anyhow, Result};
+use log::{LevelFilter, Log, Metadata, Record};
+use std::{env, fmt::Display, str::FromStr};
+
+/// Environment variable with the log level, used when no level is passed explicitly
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// Diagnostics go to stderr, answers stay alone on stdout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Level {
+    Quiet,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn filter(self) -> LevelFilter {
+        match self {
+            Level::Quiet => LevelFilter::Off,
+            Level::Info => LevelFilter::Info,
+            Level::Debug => LevelFilter::Debug,
+            Level::Trace => LevelFilter::Trace,
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "quiet" => Ok(Level::Quiet),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(anyhow!(
+                "Log level should be quiet, info, debug or trace, got {s:?}"
+            )),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Level::Quiet => "quiet",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "[{} {}] {}",
+                record.level().as_str().to_ascii_lowercase(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Resolves the level, explicit one wins over the environment and the default
+pub fn level(level: Option<Level>) -> Result<Level> {
+    match (level, env::var(LOG_ENV)) {
+        (Some(level), _) => Ok(level),
+        (None, Ok(value)) if !value.is_empty() => value.parse(),
+        (None, _) => Ok(Level::default()),
+    }
+}
+
+/// Installs the stderr logger, calling it again only changes the level
+pub fn init(level: Option<Level>) -> Result<Level> {
+    let level = self::level(level)?;
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(level.filter());
+    Ok(level)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_from_str() {
+        assert_eq!("quiet".parse::<Level>().unwrap(), Level::Quiet);
+        assert_eq!("DEBUG".parse::<Level>().unwrap(), Level::Debug);
+        assert_eq!(
+            Level::Trace.to_string().parse::<Level>().unwrap(),
+            Level::Trace
+        );
+        assert!("loud".parse::<Level>().is_err());
+        assert_eq!(Level::Quiet.filter(), LevelFilter::Off);
+    }
+
+    #[test]
+    fn test_explicit_level_wins() {
+        assert_eq!(level(Some(Level::Trace)).unwrap(), Level::Trace);
+    }
+}
@@ -1,47 +1,293 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
 
-#[derive(Debug)]
-pub struct NumberTrie {
-    pub number: Option<usize>,
-    pub children: HashMap<char, NumberTrie>,
+/// Keys that can be split into the symbols a [`Trie`] branches on, like a `&str` into chars
+pub trait TrieKey<K> {
+    fn symbols(self) -> impl Iterator<Item = K>;
 }
 
-impl NumberTrie {
-    pub fn new() -> Self {
-        NumberTrie {
-            number: None,
+impl TrieKey<char> for &str {
+    fn symbols(self) -> impl Iterator<Item = char> {
+        self.chars()
+    }
+}
+
+impl TrieKey<char> for String {
+    fn symbols(self) -> impl Iterator<Item = char> {
+        self.chars().collect::<Vec<_>>().into_iter()
+    }
+}
+
+impl<K: Clone> TrieKey<K> for &[K] {
+    fn symbols(self) -> impl Iterator<Item = K> {
+        self.iter().cloned()
+    }
+}
+
+impl<K> TrieKey<K> for Vec<K> {
+    fn symbols(self) -> impl Iterator<Item = K> {
+        self.into_iter()
+    }
+}
+
+/// Prefix tree mapping sequences of `K` to values
+#[derive(Debug, Clone)]
+pub struct Trie<K, V> {
+    value: Option<V>,
+    children: HashMap<K, Trie<K, V>>,
+}
+
+/// Trie of spelled numbers
+pub type NumberTrie = Trie<char, usize>;
+/// Scanner for spelled numbers
+pub type NumberAutomaton = AhoCorasick<char, usize>;
+
+impl<K, V> Default for Trie<K, V> {
+    fn default() -> Self {
+        Self {
+            value: None,
             children: HashMap::new(),
         }
     }
 }
 
-impl Default for NumberTrie {
+impl<K: Eq + Hash, V> Trie<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keys are stored back to front, for scanning from the end
+    pub fn from_reversed<T: TrieKey<K>>(iter: impl IntoIterator<Item = (T, V)>) -> Self {
+        let mut root = Self::new();
+        for (key, value) in iter {
+            let mut symbols = key.symbols().collect::<Vec<_>>();
+            symbols.reverse();
+            root.insert(symbols, value);
+        }
+        root
+    }
+
+    /// Value stored right at this node
+    pub fn value(&self) -> Option<&V> {
+        self.value.as_ref()
+    }
+
+    /// Subtree after one more symbol
+    pub fn child(&self, symbol: &K) -> Option<&Self> {
+        self.children.get(symbol)
+    }
+
+    fn node(&self, key: impl TrieKey<K>) -> Option<&Self> {
+        key.symbols()
+            .try_fold(self, |node, symbol| node.children.get(&symbol))
+    }
+
+    /// Returns the value previously stored under `key`; empty keys are skipped, as they would match everywhere
+    pub fn insert(&mut self, key: impl TrieKey<K>, value: V) -> Option<V> {
+        let mut symbols = key.symbols().peekable();
+        symbols.peek()?;
+        let node = symbols.fold(self, |node, symbol| {
+            node.children.entry(symbol).or_default()
+        });
+        node.value.replace(value)
+    }
+
+    pub fn get(&self, key: impl TrieKey<K>) -> Option<&V> {
+        self.node(key)?.value()
+    }
+
+    /// Removes the value under `key` together with the branches left empty
+    pub fn remove(&mut self, key: impl TrieKey<K>) -> Option<V> {
+        let symbols = key.symbols().collect::<Vec<_>>();
+        self.remove_symbols(&symbols)
+    }
+
+    fn remove_symbols(&mut self, symbols: &[K]) -> Option<V> {
+        let Some((first, rest)) = symbols.split_first() else {
+            return self.value.take();
+        };
+        let child = self.children.get_mut(first)?;
+        let value = child.remove_symbols(rest);
+        if child.value.is_none() && child.children.is_empty() {
+            self.children.remove(first);
+        }
+        value
+    }
+
+    /// Whether any stored key starts with `prefix`
+    pub fn contains_prefix(&self, prefix: impl TrieKey<K>) -> bool {
+        self.node(prefix).is_some()
+    }
+
+    /// Longest stored key that `text` starts with, as the number of its symbols and its value
+    pub fn longest_prefix(&self, text: impl TrieKey<K>) -> Option<(usize, &V)> {
+        let mut longest = self.value().map(|value| (0, value));
+        let mut node = self;
+        for (i, symbol) in text.symbols().enumerate() {
+            let Some(child) = node.children.get(&symbol) else {
+                break;
+            };
+            node = child;
+            if let Some(value) = node.value() {
+                longest = Some((i + 1, value));
+            }
+        }
+        longest
+    }
+
+    /// Stored keys with their values, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Vec<K>, &V)>
+    where
+        K: Clone,
+    {
+        let mut stack = vec![(vec![], self)];
+        std::iter::from_fn(move || {
+            while let Some((key, node)) = stack.pop() {
+                for (symbol, child) in node.children.iter() {
+                    let mut child_key = key.clone();
+                    child_key.push(symbol.clone());
+                    stack.push((child_key, child));
+                }
+                if let Some(value) = node.value() {
+                    return Some((key, value));
+                }
+            }
+            None
+        })
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = Vec<K>> + '_
+    where
+        K: Clone,
+    {
+        self.iter().map(|(key, _)| key)
+    }
+}
+
+impl<K: Eq + Hash, V, T: TrieKey<K>> FromIterator<(T, V)> for Trie<K, V> {
+    fn from_iter<I: IntoIterator<Item = (T, V)>>(iter: I) -> Self {
+        let mut root = Self::new();
+        for (key, value) in iter {
+            root.insert(key, value);
+        }
+        root
+    }
+}
+
+/// Occurrence of a key, `start..end` counts symbols of the scanned text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<V> {
+    pub start: usize,
+    pub end: usize,
+    pub value: V,
+}
+
+#[derive(Debug, Clone)]
+struct State<K> {
+    next: HashMap<K, usize>,
+    fail: usize,
+    /// Indices of the keys ending here, longest first
+    outputs: Vec<usize>,
+}
+
+impl<K> Default for State<K> {
     fn default() -> Self {
-        NumberTrie::new()
+        Self {
+            next: HashMap::new(),
+            fail: 0,
+            outputs: vec![],
+        }
     }
 }
 
-impl From<Vec<(&str, usize)>> for NumberTrie {
-    fn from(value: Vec<(&str, usize)>) -> Self {
-        let mut root = NumberTrie::default();
+/// Aho-Corasick automaton, a trie with failure links that finds every key in one pass
+#[derive(Debug, Clone)]
+pub struct AhoCorasick<K, V> {
+    states: Vec<State<K>>,
+    /// Key lengths with their values
+    keys: Vec<(usize, V)>,
+}
 
-        for (name, value) in value {
-            root.append(name, value);
+impl<K: Eq + Hash + Clone, V> AhoCorasick<K, V> {
+    fn transition(&self, mut state: usize, symbol: &K) -> usize {
+        loop {
+            if let Some(&next) = self.states[state].next.get(symbol) {
+                return next;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.states[state].fail;
         }
-        root
+    }
+
+    /// Every occurrence of the keys, overlapping ones included, ordered by where they end
+    pub fn find_iter<'a, I>(&'a self, text: I) -> impl Iterator<Item = Match<&'a V>> + 'a
+    where
+        I: IntoIterator<Item = K>,
+        I::IntoIter: 'a,
+    {
+        let mut state = 0;
+        text.into_iter().enumerate().flat_map(move |(i, symbol)| {
+            state = self.transition(state, &symbol);
+            self.states[state].outputs.iter().map(move |&key| {
+                let (len, value) = &self.keys[key];
+                Match {
+                    start: i + 1 - len,
+                    end: i + 1,
+                    value,
+                }
+            })
+        })
     }
 }
 
-impl NumberTrie {
-    pub fn append(&mut self, value: &str, number: usize) {
-        if value.is_empty() {
-            return;
+impl<K: Eq + Hash + Clone, V> From<Trie<K, V>> for AhoCorasick<K, V> {
+    fn from(trie: Trie<K, V>) -> Self {
+        let mut automaton = Self {
+            states: vec![State::default()],
+            keys: vec![],
+        };
+        // States are numbered breadth first, so a failure link always points to a finished state
+        let mut queue = VecDeque::from([(trie, 0, 0)]);
+        while let Some((node, state, depth)) = queue.pop_front() {
+            if let Some(value) = node.value {
+                automaton.states[state].outputs.push(automaton.keys.len());
+                automaton.keys.push((depth, value));
+            }
+            for (symbol, child) in node.children {
+                let next = automaton.states.len();
+                automaton.states.push(State::default());
+                automaton.states[state].next.insert(symbol, next);
+                queue.push_back((child, next, depth + 1));
+            }
         }
-        let mut node = self;
-        for char in value.chars() {
-            node = node.children.entry(char).or_default()
+        for state in 0..automaton.states.len() {
+            let transitions = automaton.states[state]
+                .next
+                .iter()
+                .map(|(symbol, &next)| (symbol.clone(), next))
+                .collect::<Vec<_>>();
+            for (symbol, next) in transitions {
+                let fail = if state == 0 {
+                    0
+                } else {
+                    automaton.transition(automaton.states[state].fail, &symbol)
+                };
+                let inherited = automaton.states[fail].outputs.clone();
+                automaton.states[next].fail = fail;
+                automaton.states[next].outputs.extend(inherited);
+            }
         }
-        node.number = Some(number);
+        automaton
+    }
+}
+
+impl<K: Eq + Hash + Clone, V, T: TrieKey<K>> FromIterator<(T, V)> for AhoCorasick<K, V> {
+    fn from_iter<I: IntoIterator<Item = (T, V)>>(iter: I) -> Self {
+        iter.into_iter().collect::<Trie<K, V>>().into()
     }
 }
 
@@ -50,7 +296,7 @@ mod tests {
     use super::*;
 
     fn create_trie() -> NumberTrie {
-        vec![
+        [
             ("one", 1),
             ("two", 2),
             ("three", 3),
@@ -61,22 +307,102 @@ mod tests {
             ("eight", 8),
             ("nine", 9),
         ]
-        .into()
+        .into_iter()
+        .collect()
     }
 
     #[test]
     fn basic_case() {
         let tr = create_trie();
 
-        assert!(!tr.children.contains_key(&'b'));
+        assert!(tr.child(&'b').is_none());
         assert_eq!(
-            tr.children
-                .get(&'f')
-                .and_then(|node| node.children.get(&'o'))
-                .and_then(|node| node.children.get(&'u'))
-                .and_then(|node| node.children.get(&'r'))
-                .and_then(|node| node.number),
-            Some(4)
+            tr.child(&'f')
+                .and_then(|node| node.child(&'o'))
+                .and_then(|node| node.child(&'u'))
+                .and_then(|node| node.child(&'r'))
+                .and_then(|node| node.value()),
+            Some(&4)
         );
+        assert_eq!(tr.get("four"), Some(&4));
+        assert_eq!(tr.get("fou"), None);
+        assert!(tr.contains_prefix("fou"));
+        assert!(!tr.contains_prefix("fur"));
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut tr: Trie<u8, &str> = Trie::new();
+        assert_eq!(tr.insert(vec![1, 2], "a"), None);
+        assert_eq!(tr.insert(&[1, 2, 3][..], "b"), None);
+        assert_eq!(tr.insert(vec![1, 2], "c"), Some("a"));
+        assert_eq!(tr.remove(vec![1, 2, 3]), Some("b"));
+        assert!(!tr.contains_prefix(vec![1, 2, 3]));
+        assert_eq!(tr.remove(vec![1]), None);
+        assert_eq!(tr.remove(vec![1, 2]), Some("c"));
+        assert!(tr.child(&1).is_none());
+    }
+
+    #[test]
+    fn test_longest_prefix() {
+        let tr: Trie<char, usize> = [("se", 0), ("seven", 7), ("six", 6)].into_iter().collect();
+        assert_eq!(tr.longest_prefix("sevenine"), Some((5, &7)));
+        assert_eq!(tr.longest_prefix("seve"), Some((2, &0)));
+        assert_eq!(tr.longest_prefix("nine"), None);
+    }
+
+    #[test]
+    fn test_keys() {
+        let mut keys = create_trie()
+            .keys()
+            .map(|key| key.into_iter().collect::<String>())
+            .collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(keys[..3], ["eight", "five", "four"]);
+        assert_eq!(keys.len(), 9);
+
+        let reversed = NumberTrie::from_reversed([("one", 1), ("two", 2)]);
+        assert_eq!(reversed.get("eno"), Some(&1));
+        assert_eq!(reversed.get("one"), None);
+    }
+
+    #[test]
+    fn test_aho_corasick() {
+        let automaton: AhoCorasick<char, &str> = [
+            ("he", "he"),
+            ("she", "she"),
+            ("his", "his"),
+            ("hers", "hers"),
+        ]
+        .into_iter()
+        .collect();
+        let found = automaton
+            .find_iter("ushers".chars())
+            .map(|m| (m.start, m.end, *m.value))
+            .collect::<Vec<_>>();
+        assert_eq!(found, vec![(1, 4, "she"), (2, 4, "he"), (2, 6, "hers")]);
+
+        let automaton: NumberAutomaton = create_trie().into();
+        let found = automaton
+            .find_iter("xtwoneight".chars())
+            .map(|m| (m.start, *m.value))
+            .collect::<Vec<_>>();
+        assert_eq!(found, vec![(1, 2), (3, 1), (5, 8)]);
+        assert_eq!(automaton.find_iter("zero".chars()).count(), 0);
+    }
+
+    #[test]
+    fn test_empty_key() {
+        let mut tr = create_trie();
+        assert_eq!(tr.insert("", 0), None);
+        assert_eq!(tr.get(""), None);
+        assert_eq!(tr.longest_prefix("zero"), None);
+
+        let automaton: NumberAutomaton = [("", 0), ("one", 1)].into_iter().collect();
+        let found = automaton
+            .find_iter("xone".chars())
+            .map(|m| (m.start, m.end, *m.value))
+            .collect::<Vec<_>>();
+        assert_eq!(found, vec![(1, 4, 1)]);
     }
 }
@@ -0,0 +1,189 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Malformed puzzle input, points at the line and column where parsing stopped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 0 when the input does not belong to a puzzle
+    pub day: u8,
+    /// 1-based line of the input
+    pub line: usize,
+    /// 1-based column in characters
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Error located at the start of `rest`, which has to be a slice of `input`
+    ///
+    /// Anything else is treated as the end of the input
+    pub fn at(day: u8, input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let offset = (rest.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + rest.len() <= input.len())
+            .unwrap_or(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = rest.lines().next().unwrap_or_default();
+        let found = match line.chars().next() {
+            _ if offset == input.len() => "end of input".to_owned(),
+            None => "end of line".to_owned(),
+            Some(ch) if ch.is_whitespace() => ch.to_string(),
+            Some(_) => line
+                .split(char::is_whitespace)
+                .next()
+                .unwrap_or(line)
+                .to_owned(),
+        };
+        Self::new(
+            day,
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            expected,
+            found,
+        )
+    }
+
+    /// Input ended before the parser found what it expected
+    pub fn end_of_input(day: u8, input: &str, expected: impl Into<String>) -> Self {
+        Self::at(day, input, &input[input.len()..], expected)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.day > 0 {
+            f.write_fmt(format_args!("Day {} input, ", self.day))?;
+        } else {
+            f.write_str("Input, ")?;
+        }
+        f.write_fmt(format_args!(
+            "line {} column {}: expected {}, found {:?}",
+            self.line, self.column, self.expected, self.found
+        ))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a token that is a slice of `input`, pointing at it on failure
+pub fn parse_token<T: FromStr>(
+    day: u8,
+    input: &str,
+    token: &str,
+    expected: impl Into<String>,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(day, input, token, expected))
+}
+
+/// Checks that non-blank lines form a rectangle of allowed characters
+pub fn check_grid(
+    day: u8,
+    input: &str,
+    allowed: impl Fn(char) -> bool,
+    expected: &str,
+) -> Result<(), ParseError> {
+    let mut width = None;
+    for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if let Some(i) = line.find(|ch| !allowed(ch)) {
+            return Err(ParseError::at(day, input, &line[i..], expected));
+        }
+        // Rows are measured in cells, one per char
+        let len = line.chars().count();
+        let width = *width.get_or_insert(len);
+        if len != width {
+            let rest = &line[line
+                .char_indices()
+                .nth(width)
+                .map_or(line.len(), |(i, _)| i)..];
+            return Err(ParseError::at(
+                day,
+                input,
+                rest,
+                format!("a row of {width} cells"),
+            ));
+        }
+    }
+    match width {
+        Some(_) => Ok(()),
+        None => Err(ParseError::end_of_input(day, input, expected)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let input = "Game 1: 3 blue\n  Game x: 4 red";
+        let rest = &input[22..];
+        let err = ParseError::at(2, input, rest, "a game id");
+        assert_eq!(err, ParseError::new(2, 2, 8, "a game id", "x:"));
+        assert_eq!(
+            err.to_string(),
+            "Day 2 input, line 2 column 8: expected a game id, found \"x:\""
+        );
+        let err = ParseError::new(0, 1, 1, "a grid cell", "x");
+        assert_eq!(
+            err.to_string(),
+            "Input, line 1 column 1: expected a grid cell, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_end_of_line_and_input() {
+        let input = "Time: 7\nDistance:\n";
+        let err = ParseError::at(6, input, &input[17..17], "a number");
+        assert_eq!((err.line, err.column), (2, 10));
+        assert_eq!(err.found, "end of line");
+        let err = ParseError::end_of_input(6, input, "Distance line");
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_parse_token() {
+        let input = "1 2x 3";
+        let value: usize = parse_token(9, input, &input[..1], "a number").unwrap();
+        assert_eq!(value, 1);
+        let err = parse_token::<usize>(9, input, &input[2..4], "a number").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (3, "2x"));
+    }
+
+    #[test]
+    fn test_check_grid() {
+        let is_cell = |ch| ch == '.' || ch == '#';
+        assert!(check_grid(11, "\n  .#.\n  #..\n", is_cell, "a cell").is_ok());
+        let err = check_grid(11, ".#.\n#.", is_cell, "a cell").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "a row of 3 cells");
+        let err = check_grid(11, ".#.\n#..#", is_cell, "a cell").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (4, "#"));
+        let err = check_grid(11, ".#.\n#x.", is_cell, "a cell").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "x."));
+        assert!(check_grid(11, "  \n", is_cell, "a cell").is_err());
+
+        let err = check_grid(0, "éa\nabc", |_| true, "a cell").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "c"));
+        assert!(check_grid(0, "éa\nab", |_| true, "a cell").is_ok());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::solution::Meta;
+use anyhow::{Context, Result};
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing to the directory with puzzle inputs
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Path that reads the input from stdin
+pub const STDIN: &str = "-";
+
+pub fn default_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("input")
+}
+
+/// Resolves inputs directory, explicit one wins over the environment and the default location
+pub fn input_dir(dir: Option<&Path>) -> PathBuf {
+    dir.map(Path::to_path_buf)
+        .or_else(|| env::var_os(INPUT_DIR_ENV).map(PathBuf::from))
+        .unwrap_or_else(default_dir)
+}
+
+pub fn input_path(meta: &Meta, dir: Option<&Path>) -> PathBuf {
+    input_dir(dir).join(format!("{}.txt", meta.input))
+}
+
+pub fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new(STDIN) {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read input from stdin")?;
+        return Ok(input);
+    }
+    fs::read_to_string(path).with_context(|| {
+        format!(
+            "Failed to read input file {}, pass --input or set {INPUT_DIR_ENV}",
+            path.display()
+        )
+    })
+}
+
+/// Loads the input for a day, `path` is a file or `-` for stdin, otherwise the file is looked up in `dir`
+pub fn load(meta: &Meta, path: Option<&Path>, dir: Option<&Path>) -> Result<String> {
+    match path {
+        Some(path) => read_input(path),
+        None => read_input(&input_path(meta, dir)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const META: Meta = Meta::new(5, "Title", "default", "day5_example");
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(
+            input_path(&META, Some(Path::new("/tmp/inputs"))),
+            PathBuf::from("/tmp/inputs/day5_example.txt")
+        );
+    }
+
+    #[test]
+    fn test_missing_file_is_named() {
+        let err = load(&META, None, Some(Path::new("/nonexistent"))).unwrap_err();
+        assert!(err.to_string().contains("/nonexistent/day5_example.txt"));
+    }
+
+    #[test]
+    fn test_explicit_path() {
+        let path = env::temp_dir().join("advent_2023_puzzle_input_test.txt");
+        fs::write(&path, "1abc2").unwrap();
+        assert_eq!(load(&META, Some(&path), None).unwrap(), "1abc2");
+        fs::remove_file(path).unwrap();
+    }
+}
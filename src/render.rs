@@ -0,0 +1,103 @@
+use colored::Color;
+use std::{
+    env,
+    io::{self, IsTerminal},
+};
+
+/// Environment variable that turns colors off when set to anything but an empty string
+pub const NO_COLOR_ENV: &str = "NO_COLOR";
+
+/// Colors are only used on a terminal and when `NO_COLOR` is not set
+pub fn color_enabled() -> bool {
+    let no_color = env::var_os(NO_COLOR_ENV).is_some_and(|value| !value.is_empty());
+    !no_color && io::stdout().is_terminal()
+}
+
+/// What a piece of a rendered puzzle stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Plain,
+    /// Pipes of the main loop
+    Path,
+    Inside,
+    Outside,
+    /// Numbers next to a symbol
+    Part,
+    /// Numbers without a symbol next to them
+    Orphan,
+    /// `*` next to exactly two numbers
+    Gear,
+    Symbol,
+    /// Rows and columns that expand
+    Empty,
+    Label,
+}
+
+impl Style {
+    /// Foreground color and whether it is bold, `None` for plain text
+    fn color(self) -> Option<(Color, bool)> {
+        match self {
+            Style::Plain => None,
+            Style::Path => Some((Color::Yellow, false)),
+            Style::Inside => Some((Color::Green, true)),
+            Style::Outside => Some((Color::Blue, false)),
+            Style::Part => Some((Color::Green, false)),
+            Style::Orphan => Some((Color::Red, false)),
+            Style::Gear => Some((Color::Yellow, true)),
+            Style::Symbol => Some((Color::Cyan, false)),
+            Style::Empty => Some((Color::BrightBlack, false)),
+            Style::Label => Some((Color::Magenta, true)),
+        }
+    }
+}
+
+/// Styles text with colors, or leaves it as it is when colors are off
+///
+/// The choice stays with the painter, the global override of `colored` is left alone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Painter {
+    color: bool,
+}
+
+impl Painter {
+    pub fn new(color: bool) -> Self {
+        Self { color }
+    }
+
+    /// Colors when stdout is a terminal and `NO_COLOR` is not set
+    pub fn detect() -> Self {
+        Self::new(color_enabled())
+    }
+
+    pub fn plain() -> Self {
+        Self::new(false)
+    }
+
+    pub fn is_colored(&self) -> bool {
+        self.color
+    }
+
+    pub fn paint(&self, text: &str, style: Style) -> String {
+        match style.color() {
+            Some((color, bold)) if self.color => {
+                let bold = if bold { "1;" } else { "" };
+                format!("\x1b[{bold}{}m{text}\x1b[0m", color.to_fg_str())
+            }
+            _ => text.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paint() {
+        assert_eq!(Painter::plain().paint("7", Style::Gear), "7");
+        let painter = Painter::new(true);
+        assert_eq!(painter.paint("7", Style::Plain), "7");
+        assert_eq!(painter.paint("7", Style::Gear), "\x1b[1;33m7\x1b[0m");
+        assert_eq!(painter.paint("7", Style::Empty), "\x1b[90m7\x1b[0m");
+    }
+}
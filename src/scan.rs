@@ -0,0 +1,203 @@
+use crate::parse_error::{parse_token, ParseError};
+use std::{iter, str::FromStr};
+
+/// Integer found by [`unsigned`] or [`signed`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Integer<'a> {
+    /// Byte offset in the scanned text
+    pub offset: usize,
+    pub text: &'a str,
+}
+
+impl Integer<'_> {
+    /// `None` when the integer does not fit into `T`
+    pub fn value<T: FromStr>(&self) -> Option<T> {
+        self.text.parse().ok()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Integers<'a> {
+    text: &'a str,
+    offset: usize,
+    signed: bool,
+}
+
+impl<'a> Iterator for Integers<'a> {
+    type Item = Integer<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+        let start = (self.offset..bytes.len()).find(|&i| bytes[i].is_ascii_digit())?;
+        let end = (start..bytes.len())
+            .find(|&i| !bytes[i].is_ascii_digit())
+            .unwrap_or(bytes.len());
+        self.offset = end;
+        let start = match start.checked_sub(1) {
+            Some(sign) if self.signed && bytes[sign] == b'-' => sign,
+            _ => start,
+        };
+        Some(Integer {
+            offset: start,
+            text: &self.text[start..end],
+        })
+    }
+}
+
+/// Every run of digits, anything in between is skipped
+pub fn unsigned(text: &str) -> Integers<'_> {
+    Integers {
+        text,
+        offset: 0,
+        signed: false,
+    }
+}
+
+/// Every run of digits together with a `-` right before it
+pub fn signed(text: &str) -> Integers<'_> {
+    Integers {
+        text,
+        offset: 0,
+        signed: true,
+    }
+}
+
+/// Whitespace separated numbers, the text has to be a slice of `input`
+pub fn numbers<T: FromStr>(
+    day: u8,
+    input: &str,
+    text: &str,
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    text.split_ascii_whitespace()
+        .map(|n| parse_token(day, input, n, expected))
+        .collect()
+}
+
+/// `<label> <id>: <rest>` header, like `Game 12: ...` or `Card   3: ...`
+pub fn header<'a, T: FromStr>(
+    day: u8,
+    input: &str,
+    line: &'a str,
+    label: &str,
+) -> Result<(T, &'a str), ParseError> {
+    let rest = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::at(day, input, line, format!("`{label} <id>:`")))?
+        .trim_start();
+    let (id, rest) = rest
+        .split_once(':')
+        .ok_or_else(|| ParseError::at(day, input, rest, "`<id>:`"))?;
+    let expected = format!("a {} id", label.to_lowercase());
+    Ok((parse_token(day, input, id.trim_end(), expected)?, rest))
+}
+
+/// Comma separated `<count> <key>` pairs, like `3 blue, 4 red`
+pub fn counts<'a, T: FromStr>(
+    day: u8,
+    input: &str,
+    list: &'a str,
+    key: &str,
+) -> Result<Vec<(&'a str, T)>, ParseError> {
+    list.split(',')
+        .map(|item| {
+            let item = item.trim();
+            let (count, name) = item
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(day, input, item, format!("`<count> <{key}>`")))?;
+            Ok((name.trim(), parse_token(day, input, count, "a count")?))
+        })
+        .collect()
+}
+
+/// Blocks of lines separated by blank lines, as slices of `input`
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    let mut lines = input.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        Some((start, line))
+    });
+    iter::from_fn(move || {
+        let (start, first) = lines.find(|(_, line)| !line.trim().is_empty())?;
+        let mut end = start + first.trim_end().len();
+        for (line_start, line) in lines.by_ref() {
+            if line.trim().is_empty() {
+                break;
+            }
+            end = line_start + line.trim_end().len();
+        }
+        Some(&input[start..end])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        let line = "x=12, y=-3..-40 7-2";
+        let found = unsigned(line)
+            .map(|i| (i.offset, i.text))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![(2, "12"), (9, "3"), (13, "40"), (16, "7"), (18, "2")]
+        );
+        let values = signed(line)
+            .filter_map(|i| i.value::<i8>())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![12, -3, -40, 7, -2]);
+        assert_eq!(unsigned("999").next().unwrap().value::<u8>(), None);
+        assert_eq!(signed("- -").count(), 0);
+    }
+
+    #[test]
+    fn test_numbers() {
+        let input = "Time: 7 15\nDistance: 9 4o";
+        assert_eq!(
+            numbers::<usize>(6, input, &input[5..10], "a number"),
+            Ok(vec![7, 15])
+        );
+        let err = numbers::<usize>(6, input, &input[20..], "a number").unwrap_err();
+        assert_eq!(err, ParseError::new(6, 2, 13, "a number", "4o"));
+    }
+
+    #[test]
+    fn test_header() {
+        let input = "Card   3: 1 2 | 3";
+        assert_eq!(header(4, input, input, "Card"), Ok((3, " 1 2 | 3")));
+        let err = header::<usize>(2, "Game x: 1 red", "Game x: 1 red", "Game").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 1, 6, "a game id", "x"));
+        let err = header::<usize>(2, "Game 1 1 red", "Game 1 1 red", "Game").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (6, "`<id>:`"));
+        let err = header::<usize>(4, "Crad 1: 2", "Crad 1: 2", "Card").unwrap_err();
+        assert_eq!(err.expected, "`Card <id>:`");
+    }
+
+    #[test]
+    fn test_counts() {
+        let input = "3 blue, 4 red";
+        assert_eq!(
+            counts(2, input, input, "color"),
+            Ok(vec![("blue", 3), ("red", 4)])
+        );
+        let err = counts::<usize>(2, "3 blue, red", "3 blue, red", "color").unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (9, "`<count> <color>`")
+        );
+        let err = counts::<usize>(2, "x blue", "x blue", "color").unwrap_err();
+        assert_eq!(err.found, "x");
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "seeds: 1 2\n\n  a map:\n  1 2 3\n  \n\nb map:\n4 5 6\n";
+        assert_eq!(
+            sections(input).collect::<Vec<_>>(),
+            vec!["seeds: 1 2", "  a map:\n  1 2 3", "b map:\n4 5 6"]
+        );
+        assert_eq!(sections("\n \n").count(), 0);
+    }
+}
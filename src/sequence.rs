@@ -0,0 +1,151 @@
+/// Rows of differences, from the numbers themselves down to the first row of all zeroes
+///
+/// Panics when a difference overflows, see [`checked_difference_table`]
+pub fn difference_table(numbers: &[isize]) -> Vec<Vec<isize>> {
+    checked_difference_table(numbers).expect("difference of numbers overflows")
+}
+
+/// `None` when a difference overflows
+pub fn checked_difference_table(numbers: &[isize]) -> Option<Vec<Vec<isize>>> {
+    let mut table = vec![numbers.to_vec()];
+    while let Some(last) = table.last().filter(|row| !row.iter().all(|&n| n == 0)) {
+        let row = last
+            .windows(2)
+            .map(|pair| pair[1].checked_sub(pair[0]))
+            .collect::<Option<_>>()?;
+        table.push(row);
+    }
+    Some(table)
+}
+
+/// Value after the last number, `None` when it overflows
+pub fn extrapolate_next(table: &[Vec<isize>]) -> Option<isize> {
+    table
+        .iter()
+        .rev()
+        .filter_map(|row| row.last())
+        .try_fold(0isize, |next, &last| next.checked_add(last))
+}
+
+/// Value before the first number, `None` when it overflows
+pub fn extrapolate_prev(table: &[Vec<isize>]) -> Option<isize> {
+    table
+        .iter()
+        .rev()
+        .filter_map(|row| row.first())
+        .try_fold(0isize, |prev, &first| first.checked_sub(prev))
+}
+
+/// Degree of the polynomial behind the numbers, `None` when there are too few of them to tell
+///
+/// Constants, zero included, are of degree 0
+pub fn degree(numbers: &[isize]) -> Option<usize> {
+    let table = checked_difference_table(numbers)?;
+    let zeroes = table.last()?;
+    (!zeroes.is_empty()).then(|| table.len().saturating_sub(2))
+}
+
+/// Lowest degree polynomial going through numbers read at `0, 1, 2, ...`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    /// Newton's forward form, `p(n) = Σ coefficients[k] * C(n, k)`
+    coefficients: Vec<isize>,
+}
+
+impl Polynomial {
+    /// Panics when a difference overflows, see [`Polynomial::checked_fit`]
+    pub fn fit(numbers: &[isize]) -> Self {
+        Self::checked_fit(numbers).expect("difference of numbers overflows")
+    }
+
+    pub fn checked_fit(numbers: &[isize]) -> Option<Self> {
+        let mut coefficients: Vec<isize> = checked_difference_table(numbers)?
+            .iter()
+            .filter_map(|row| row.first().copied())
+            .collect();
+        while coefficients.last() == Some(&0) {
+            coefficients.pop();
+        }
+        Some(Self { coefficients })
+    }
+
+    /// Newton form coefficients, the first value of every row of differences
+    pub fn coefficients(&self) -> &[isize] {
+        &self.coefficients
+    }
+
+    /// Value at any index, negative ones come before the first number
+    ///
+    /// Panics when the value overflows, see [`Polynomial::checked_at`]
+    pub fn at(&self, index: isize) -> isize {
+        self.checked_at(index)
+            .expect("value of the polynomial overflows")
+    }
+
+    /// `None` when the value or a step on the way to it overflows
+    pub fn checked_at(&self, index: isize) -> Option<isize> {
+        let index = index as i128;
+        let mut value: i128 = 0;
+        // C(index, k), stays an integer for negative indices too
+        let mut binomial: i128 = 1;
+        for (k, &coefficient) in self.coefficients.iter().enumerate() {
+            let k = k as i128;
+            value = value.checked_add((coefficient as i128).checked_mul(binomial)?)?;
+            binomial = binomial.checked_mul(index - k)? / (k + 1);
+        }
+        value.try_into().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_difference_table() {
+        assert_eq!(
+            difference_table(&[1, 3, 6, 10]),
+            vec![vec![1, 3, 6, 10], vec![2, 3, 4], vec![1, 1], vec![0]]
+        );
+        assert_eq!(difference_table(&[]), vec![Vec::<isize>::new()]);
+        assert_eq!(checked_difference_table(&[isize::MIN, isize::MAX]), None);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let table = difference_table(&[10, 13, 16, 21, 30, 45]);
+        assert_eq!(extrapolate_next(&table), Some(68));
+        assert_eq!(extrapolate_prev(&table), Some(5));
+        let table = difference_table(&[isize::MAX - 1, isize::MAX]);
+        assert_eq!(extrapolate_next(&table), None);
+        assert_eq!(extrapolate_prev(&table), Some(isize::MAX - 2));
+    }
+
+    #[test]
+    fn test_degree() {
+        assert_eq!(degree(&[0, 3, 6, 9, 12, 15]), Some(1));
+        assert_eq!(degree(&[1, 3, 6, 10, 15, 21]), Some(2));
+        assert_eq!(degree(&[7, 7]), Some(0));
+        assert_eq!(degree(&[0, 0]), Some(0));
+        assert_eq!(degree(&[1, 2]), None);
+        assert_eq!(degree(&[]), None);
+    }
+
+    #[test]
+    fn test_polynomial() {
+        let triangle = Polynomial::fit(&[1, 3, 6, 10, 15, 21]);
+        assert_eq!(triangle.coefficients(), &[1, 2, 1]);
+        assert_eq!(triangle.at(6), 28);
+        assert_eq!(triangle.at(-1), 0);
+        assert_eq!(triangle.at(-5), 6);
+        let history = Polynomial::fit(&[10, 13, 16, 21, 30, 45]);
+        assert_eq!((history.at(-1), history.at(6)), (5, 68));
+        assert_eq!(Polynomial::fit(&[0, 0]).at(100), 0);
+        assert!(Polynomial::fit(&[]).coefficients().is_empty());
+
+        let cubes = Polynomial::fit(&[0, 1, 8, 27]);
+        assert_eq!(cubes.checked_at(1_000_000), Some(1_000_000_000_000_000_000));
+        assert_eq!(cubes.checked_at(isize::MAX / 2), None);
+        assert_eq!(Polynomial::checked_fit(&[isize::MAX, isize::MIN]), None);
+    }
+}
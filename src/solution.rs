@@ -0,0 +1,257 @@
+use anyhow::{anyhow, Result};
+use once_cell::sync::Lazy;
+use std::{any::Any, fmt::Display, str::FromStr};
+
+pub type Answer = String;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(anyhow!("Part should be 1 or 2, got {s:?}")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Meta {
+    pub day: u8,
+    pub title: &'static str,
+    pub variant: &'static str,
+    /// File name of the puzzle input without extension
+    pub input: &'static str,
+    /// Left out of runs over every variant, it only runs when asked for by name
+    pub slow: bool,
+    /// Answers the other variants of the day are compared against
+    pub reference: bool,
+}
+
+impl Meta {
+    pub const fn new(
+        day: u8,
+        title: &'static str,
+        variant: &'static str,
+        input: &'static str,
+    ) -> Self {
+        Self {
+            day,
+            title,
+            variant,
+            input,
+            slow: false,
+            reference: false,
+        }
+    }
+
+    pub const fn slow(self) -> Self {
+        Self { slow: true, ..self }
+    }
+
+    pub const fn reference(self) -> Self {
+        Self {
+            reference: true,
+            ..self
+        }
+    }
+}
+
+pub trait Solution {
+    type Input: Any + Send + Sync;
+
+    const META: Meta;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer>;
+}
+
+pub struct Parsed(Box<dyn Any + Send + Sync>);
+
+pub trait DynSolution: Send + Sync {
+    fn meta(&self) -> Meta;
+
+    fn parse(&self, input: &str) -> Result<Parsed>;
+
+    fn part_one(&self, input: &Parsed) -> Result<Answer>;
+
+    fn part_two(&self, input: &Parsed) -> Result<Answer>;
+
+    fn run(&self, part: Part, input: &Parsed) -> Result<Answer> {
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<(Answer, Answer)> {
+        let parsed = self.parse(input)?;
+        Ok((self.part_one(&parsed)?, self.part_two(&parsed)?))
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Send + Sync,
+{
+    fn meta(&self) -> Meta {
+        S::META
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed> {
+        Solution::parse(self, input).map(|parsed| Parsed(Box::new(parsed)))
+    }
+
+    fn part_one(&self, input: &Parsed) -> Result<Answer> {
+        Solution::part_one(self, downcast::<S>(input)?)
+    }
+
+    fn part_two(&self, input: &Parsed) -> Result<Answer> {
+        Solution::part_two(self, downcast::<S>(input)?)
+    }
+}
+
+fn downcast<S: Solution>(input: &Parsed) -> Result<&S::Input> {
+    input.0.downcast_ref().ok_or_else(|| {
+        anyhow!(
+            "Input was not parsed by day {} ({})",
+            S::META.day,
+            S::META.variant
+        )
+    })
+}
+
+#[derive(Default)]
+pub struct Registry {
+    solutions: Vec<Box<dyn DynSolution>>,
+}
+
+impl Registry {
+    pub fn register(&mut self, solution: impl DynSolution + 'static) {
+        self.solutions.push(Box::new(solution));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DynSolution> {
+        self.solutions.iter().map(|s| s.as_ref())
+    }
+
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self.iter().map(|s| s.meta().day).collect();
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    pub fn variants(&self, day: u8) -> impl Iterator<Item = &dyn DynSolution> {
+        self.iter().filter(move |s| s.meta().day == day)
+    }
+
+    /// Finds a solution for the day, the first registered variant that is not slow is the default one
+    pub fn find(&self, day: u8, variant: Option<&str>) -> Result<&dyn DynSolution> {
+        let mut variants = self.variants(day).peekable();
+        if variants.peek().is_none() {
+            return Err(anyhow!("Day {day} is not registered"));
+        }
+        match variant {
+            Some(name) => variants
+                .find(|s| s.meta().variant == name)
+                .ok_or_else(|| anyhow!("Day {day} has no variant {name:?}")),
+            None => variants
+                .find(|s| !s.meta().slow)
+                .ok_or_else(|| anyhow!("Day {day} has only slow variants, pick one by name")),
+        }
+    }
+}
+
+pub static REGISTRY: Lazy<Registry> = Lazy::new(|| {
+    let mut registry = Registry::default();
+    crate::day01::register(&mut registry);
+    crate::day02::register(&mut registry);
+    crate::day03::register(&mut registry);
+    crate::day04::register(&mut registry);
+    crate::day05::register(&mut registry);
+    crate::day06::register(&mut registry);
+    crate::day07::register(&mut registry);
+    crate::day08::register(&mut registry);
+    crate::day09::register(&mut registry);
+    crate::day10::register(&mut registry);
+    crate::day11::register(&mut registry);
+    registry
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_day_is_registered() {
+        assert_eq!(REGISTRY.days(), (1..=11).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn variants_are_registered() {
+        let variants = |day| {
+            REGISTRY
+                .variants(day)
+                .map(|s| s.meta().variant)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            variants(5),
+            vec!["cut_ranges", "path_compressed", "bruteforce"]
+        );
+        assert_eq!(variants(10), vec!["flood_fill", "scanline"]);
+    }
+
+    #[test]
+    fn find_defaults_to_first_variant() {
+        assert_eq!(REGISTRY.find(5, None).unwrap().meta().variant, "cut_ranges");
+        assert!(REGISTRY.find(5, Some("bruteforce")).unwrap().meta().slow);
+        assert_eq!(
+            REGISTRY.find(10, Some("scanline")).unwrap().meta().variant,
+            "scanline"
+        );
+        assert!(REGISTRY.find(10, Some("unknown")).is_err());
+        assert!(REGISTRY.find(25, None).is_err());
+    }
+
+    #[test]
+    fn part_from_str() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn parsed_input_is_bound_to_its_solution() {
+        let parsed = REGISTRY
+            .find(10, Some("scanline"))
+            .unwrap()
+            .parse("S")
+            .unwrap();
+        assert!(REGISTRY.find(9, None).unwrap().part_one(&parsed).is_err());
+    }
+}